@@ -0,0 +1,33 @@
+//! シードと難易度からステージを生成してRONで出力する
+//!
+//! 使い方: cargo run --example generate_stage -- <seed> <difficulty 0.0～1.0> [<width> <height>] > assets/stages/generated.ron
+//!
+//! フィールドの大きさを省略した場合はゲームのフィールドと同じ大きさで生成する
+
+use wave_crossing_game::core::{count_head_on_conflicts, generate_stage, FieldSize};
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let seed = args.get(1).and_then(|arg| arg.parse().ok()).unwrap_or(1);
+    let difficulty = args.get(2).and_then(|arg| arg.parse().ok()).unwrap_or(0.5);
+    let default_field = FieldSize::default();
+    let field = FieldSize {
+        width: args.get(3).and_then(|arg| arg.parse().ok()).unwrap_or(default_field.width),
+        height: args.get(4).and_then(|arg| arg.parse().ok()).unwrap_or(default_field.height),
+    };
+
+    let stage = generate_stage(seed, difficulty, field);
+    for (index, wave) in stage.waves.iter().enumerate() {
+        eprintln!(
+            "wave {}: {} monsters, {} head-on lanes",
+            index,
+            wave.monsters.len(),
+            count_head_on_conflicts(wave)
+        );
+    }
+
+    match stage.to_ron_string() {
+        Ok(content) => println!("{}", content),
+        Err(e) => eprintln!("Failed to serialize stage: {}", e),
+    }
+}
@@ -13,3 +13,11 @@ pub const COLLISION_THRESHOLD: f32 = 32.0; // 衝突判定距離（ピクセル
 pub const GRID_COLOR: (f32, f32, f32) = (0.3, 0.3, 0.3); // グリッド線の色
 pub const MONSTER_COLOR: (f32, f32, f32) = (1.0, 0.3, 0.3); // モンスターの色
 pub const ITEM_COLOR: (f32, f32, f32) = (0.2, 0.8, 0.2); // アイテムの色
+pub const ITEM_SNAP_COLOR: (f32, f32, f32) = (0.2, 0.6, 0.9); // Snapモードのぐるぐる床の色
+pub const TIDE_COLOR: (f32, f32, f32, f32) = (0.2, 0.4, 0.9, 0.35); // 潮の帯の色（半透明）
+
+// アニメーション
+pub const OBSTACLE_CLEAR_ANIMATION_DURATION: f32 = 0.4; // 障害物が取り除かれて消えるまでの時間（秒）
+
+// 保存ファイル
+pub const DAILY_LEADERBOARD_PATH: &str = "daily_leaderboard.ron"; // デイリーチャレンジの記録ファイル
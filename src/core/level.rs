@@ -1,6 +1,13 @@
 /// レベルデザイン用のパラメータ
 /// ゲームバランス調整はこのファイルで行う
 
+// ========================================
+// 乱数関連
+// ========================================
+
+/// ステージでシードが指定されていない場合の乱数シード
+pub const DEFAULT_RNG_SEED: u64 = 20251101;
+
 // ========================================
 // モンスター関連
 // ========================================
@@ -11,10 +18,21 @@ pub const MONSTER_SPEED: f32 = 100.0;
 /// 画面端での待機時間（秒）
 pub const STAGING_DURATION: f32 = 2.0;
 
+/// 1レーンあたりの待機列の上限
+/// これを超えてモンスターが並ぶと虚が増加する
+pub const STAGING_QUEUE_LIMIT: usize = 3;
+
 /// モンスターの待機時間閾値（秒）
 /// この時間以上停止すると消滅する
 pub const WAIT_THRESHOLD: f32 = 10.0;
 
+/// スポーン予告の先読み時間（秒）
+/// この時間内にスポーン予定のモンスターを画面端に表示する（ステージファイルで上書きできる）
+pub const SPAWN_TELEGRAPH_LOOK_AHEAD: f32 = 5.0;
+
+/// 次のWaveを早めに呼んだときの魂のボーナス
+pub const EARLY_CALL_SPIRIT_BONUS: f32 = 5.0;
+
 // ========================================
 // プレイヤーゲージ関連
 // ========================================
@@ -25,7 +43,7 @@ pub const SPIRIT_MAX: f32 = 100.0;
 /// 魂（Spirit）ゲージの初期値
 pub const SPIRIT_INITIAL: f32 = 50.0;
 
-/// モンスターがゴールに到達したときの魂の増加量
+/// モンスターがゴールに到達したときの魂の増加量（モンスター定義で上書き可能）
 pub const SPIRIT_GAIN_PER_GOAL: f32 = 10.0;
 
 /// 虚（Void）ゲージの最大値
@@ -34,6 +52,87 @@ pub const VOID_MAX: f32 = 100.0;
 /// モンスターが待機時間切れで消滅したときの虚の増加量
 pub const VOID_GAIN_PER_DESPAWN: f32 = 5.0;
 
+/// 待機列が上限を超えたときの虚の増加量
+pub const VOID_GAIN_PER_LANE_OVERFLOW: f32 = 2.0;
+
+/// モンスターが誤った出口から出たときの虚の増加量
+pub const VOID_GAIN_PER_WRONG_EXIT: f32 = 3.0;
+
+// ========================================
+// エンドレスモード関連
+// ========================================
+
+/// 最初のWaveのモンスター数
+pub const ENDLESS_BASE_MONSTER_COUNT: usize = 3;
+
+/// Waveごとに増えるモンスター数
+pub const ENDLESS_MONSTERS_PER_WAVE: usize = 1;
+
+/// 新しい種類のモンスターが出現するようになるまでのWave数
+pub const ENDLESS_WAVES_PER_NEW_KIND: u32 = 3;
+
+/// Waveごとのモンスター速度の増加率（1Waveあたり）
+pub const ENDLESS_SPEED_GROWTH: f32 = 0.05;
+
+/// モンスター速度の倍率の上限
+pub const ENDLESS_MAX_SPEED_MULTIPLIER: f32 = 2.0;
+
+/// 最初のWaveのスポーン間隔（秒）
+pub const ENDLESS_BASE_SPAWN_INTERVAL: f32 = 2.0;
+
+/// スポーン間隔の下限（秒）
+pub const ENDLESS_MIN_SPAWN_INTERVAL: f32 = 0.6;
+
+/// Waveごとのスポーン間隔の縮小率
+pub const ENDLESS_SPAWN_INTERVAL_DECAY: f32 = 0.95;
+
+/// 前のWaveがいなくなってから次のWaveが始まるまでの時間（秒）
+pub const ENDLESS_WAVE_BREAK: f32 = 3.0;
+
+/// 障害物が追加されるWaveの間隔
+pub const ENDLESS_OBSTACLE_WAVE_INTERVAL: u32 = 4;
+
+// ========================================
+// デイリーチャレンジ関連
+// ========================================
+
+/// モンスター速度の倍率の範囲（最小, 最大）
+pub const DAILY_SPEED_MULTIPLIER_RANGE: (f32, f32) = (0.9, 1.3);
+
+/// スポーン間隔の倍率の範囲（最小, 最大）
+pub const DAILY_SPAWN_INTERVAL_MULTIPLIER_RANGE: (f32, f32) = (0.7, 1.1);
+
+/// 待機時間閾値の倍率の範囲（最小, 最大）
+pub const DAILY_WAIT_THRESHOLD_MULTIPLIER_RANGE: (f32, f32) = (0.7, 1.2);
+
+/// 開始時に置かれる障害物の最大数
+pub const DAILY_MAX_STARTING_OBSTACLES: usize = 4;
+
+// ========================================
+// ステージ生成関連
+// ========================================
+
+/// 生成するWave数の範囲（難易度0, 難易度1）
+pub const GENERATOR_WAVE_COUNT_RANGE: (usize, usize) = (3, 8);
+
+/// 1Waveあたりのモンスター数の範囲（難易度0, 難易度1）
+pub const GENERATOR_MONSTERS_PER_WAVE_RANGE: (usize, usize) = (3, 12);
+
+/// 使用するレーン数の範囲（各軸、難易度0, 難易度1）
+pub const GENERATOR_LANE_COUNT_RANGE: (usize, usize) = (3, 10);
+
+/// Wave内のスポーン間隔の範囲（秒、難易度0, 難易度1）
+pub const GENERATOR_SPAWN_INTERVAL_RANGE: (f32, f32) = (2.5, 0.8);
+
+/// Wave間の休憩時間の範囲（秒、難易度0, 難易度1）
+pub const GENERATOR_WAVE_BREAK_RANGE: (f32, f32) = (6.0, 2.0);
+
+/// 障害物の最大数（難易度1のときの数）
+pub const GENERATOR_MAX_OBSTACLES: usize = 8;
+
+/// 1Waveあたりの正面衝突（同じレーンを逆向きに進む組）の上限（難易度0, 難易度1）
+pub const GENERATOR_HEAD_ON_LIMIT_RANGE: (usize, usize) = (0, 3);
+
 // ========================================
 // アイテム関連
 // ========================================
@@ -45,8 +144,32 @@ pub const ITEM_PLACEMENT_COST: f32 = 30.0;
 // 障害物関連
 // ========================================
 
+/// Wave中の障害物イベントの予告時間（秒）
+/// イベントの実行前にこの時間だけ対象のマスに警告を表示する
+pub const OBSTACLE_EVENT_TELEGRAPH_TIME: f32 = 2.0;
+
+// 以下はobstacles.ronで指定されていない場合のデフォルト値
+
 /// 泥沼の速度倍率
 pub const SWAMP_SPEED_MULTIPLIER: f32 = 0.1;
 
+/// 風で押し出すマス数
+pub const WIND_PUSH_DISTANCE: i32 = 1;
+
 /// 風の押し出しアニメーション時間（秒）
 pub const WIND_PUSH_DURATION: f32 = 0.2;
+
+/// コンベアが運ぶ速さ（px/秒）
+pub const CONVEYOR_SPEED: f32 = 60.0;
+
+/// ワープ後に再びワープできるようになるまでの時間（秒）
+pub const TELEPORT_COOLDOWN: f32 = 0.5;
+
+/// 祠のご利益が届く範囲（祠のマスからのマス数）
+pub const SHRINE_RADIUS: i32 = 1;
+
+/// 祠のそばを通ったモンスターがゴールしたときの魂のボーナス
+pub const SHRINE_SPIRIT_BONUS: f32 = 10.0;
+
+/// 取り除ける障害物を1回削るときの魂コスト
+pub const OBSTACLE_CLEAR_COST: f32 = 10.0;
@@ -2,7 +2,11 @@ pub mod types;
 pub mod config;
 pub mod level;
 pub mod stage_asset;
+pub mod rng;
+pub mod stage_generator;
 
 pub use types::*;
 pub use config::*;
 pub use stage_asset::*;
+pub use rng::GameRng;
+pub use stage_generator::{generate_stage, count_head_on_conflicts, FieldSize};
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use super::level;
+
+/// ゲームプレイ用の乱数生成器（リソース）
+///
+/// シードを固定することで、ランダムなWaveや風の向きなどを再現できる
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// 現在のシード
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// シードを設定し直して乱数列を最初からやり直す
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// Waveの生成用に、Wave番号ごとに独立した乱数生成器を作る
+    ///
+    /// 風などプレイ中に消費される乱数列とは別なので、同じシードなら遊び方に関係なく同じWaveになる。
+    /// 日付のように連続したシードでも別のシードのWaveと重ならないように、シードとWave番号を混ぜてから使う
+    pub fn for_wave(seed: u64, wave_number: u32) -> Self {
+        Self::new(splitmix64(splitmix64(seed) ^ u64::from(wave_number)))
+    }
+}
+
+/// splitmix64の混合関数（1ビットだけ違う値どうしでも、まったく違う値になる）
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(level::DEFAULT_RNG_SEED)
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn first_values(mut rng: GameRng) -> Vec<u64> {
+        (0..8).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn wave_streams_are_deterministic() {
+        assert_eq!(first_values(GameRng::for_wave(42, 3)), first_values(GameRng::for_wave(42, 3)));
+        assert_ne!(first_values(GameRng::for_wave(42, 3)), first_values(GameRng::for_wave(43, 3)));
+        assert_ne!(first_values(GameRng::for_wave(42, 3)), first_values(GameRng::for_wave(42, 4)));
+        // 下位ビットだけ違うシードでも、Wave番号をずらした乱数列と重ならない
+        assert_ne!(first_values(GameRng::for_wave(42, 3)), first_values(GameRng::for_wave(43, 2)));
+    }
+
+    #[test]
+    fn adjacent_dates_and_waves_use_distinct_streams() {
+        let mut firsts = HashSet::new();
+        let mut count = 0;
+        for seed in 20261001..=20261031 {
+            for wave_number in 0..20 {
+                firsts.insert(GameRng::for_wave(seed, wave_number).next_u64());
+                count += 1;
+            }
+        }
+        assert_eq!(firsts.len(), count);
+    }
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut reseeded = GameRng::new(1);
+        reseeded.next_u64();
+        reseeded.reseed(42);
+        assert_eq!(first_values(GameRng::new(42)), first_values(reseeded));
+        assert_ne!(first_values(GameRng::new(42)), first_values(GameRng::new(43)));
+    }
+}
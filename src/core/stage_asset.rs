@@ -2,19 +2,48 @@ use bevy::{
     asset::{io::Reader, AssetLoader, LoadContext},
     prelude::*,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::feature::monster::{StageLevel, WaveDefinition, MonsterDefinition, MonsterKind};
-use crate::feature::obstacle::ObstacleDefinition;
+use crate::feature::monster::{
+    StageLevel, WaveDefinition, MonsterDefinition, MonsterDefinitionEntry, MonsterKind, resolve_monster_definitions,
+};
+use crate::feature::obstacle::{ObstacleCatalogEntry, ObstacleDefinition, TideDefinition};
 
 /// ステージレベルファイルの構造
-#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
 pub struct StageLevelAsset {
     pub stage: u32,
     pub level: u32,
+    /// ゲームプレイ用乱数のシード（省略時はlevel::DEFAULT_RNG_SEED）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// ゲームモード（省略時はwavesに従うFixed）
+    #[serde(default)]
+    pub mode: StageMode,
+    /// スポーン予告の先読み時間（秒、省略時はlevel::SPAWN_TELEGRAPH_LOOK_AHEAD）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spawn_telegraph_look_ahead: Option<f32>,
+    /// 斜め移動モード（trueなら斜め方向のスポーンを使え、ぐるぐる床が45度ずつ曲げる）
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub diagonal_movement: bool,
     pub waves: Vec<WaveDefinition>,
     #[serde(default)]
     pub obstacles: Vec<ObstacleDefinition>,
+    /// 潮の満ち引き
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tides: Vec<TideDefinition>,
+}
+
+/// ステージのゲームモード
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StageMode {
+    /// wavesに定義されたWaveを順に出現させる
+    #[default]
+    Fixed,
+    /// wavesを無視して、Waveを自動生成し続ける（虚が満タンになるまで終わらない）
+    Endless,
+    /// 日付から決まるシードと変化要素でエンドレスモードを遊ぶ（seedは無視される）
+    Daily,
 }
 
 impl StageLevelAsset {
@@ -25,6 +54,28 @@ impl StageLevelAsset {
             waves: self.waves.clone(),
         }
     }
+
+    /// ステージファイルのRON文字列を読み込み、スポーンパターンを個々のスポーン定義に展開する
+    pub fn from_ron_str(content: &str) -> Result<Self, ron::error::SpannedError> {
+        // Option型のフィールドをSome(...)で囲まずに書けるようにする
+        let mut asset: StageLevelAsset = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(content)?;
+
+        // スポーンパターンを個々のスポーン定義に展開
+        for wave in &mut asset.waves {
+            wave.expand_patterns();
+        }
+
+        Ok(asset)
+    }
+
+    /// ステージファイルと同じ形式のRON文字列に変換する（生成したステージの書き出し用）
+    pub fn to_ron_string(&self) -> Result<String, ron::Error> {
+        ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .to_string_pretty(self, ron::ser::PrettyConfig::new().struct_names(false))
+    }
 }
 
 #[derive(Default)]
@@ -46,10 +97,7 @@ impl AssetLoader for StageLevelAssetLoader {
         let content = std::str::from_utf8(&bytes)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
-        let asset: StageLevelAsset = ron::from_str(content)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-
-        Ok(asset)
+        StageLevelAsset::from_ron_str(content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
     }
 
     fn extensions(&self) -> &[&str] {
@@ -58,7 +106,16 @@ impl AssetLoader for StageLevelAssetLoader {
 }
 
 /// モンスター定義ファイルの構造
-#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct MonsterDefinitionsFile {
+    /// 継承元として使うテンプレート（それ自体はモンスターにならない）
+    #[serde(default)]
+    pub templates: Vec<MonsterDefinitionEntry>,
+    pub definitions: Vec<MonsterDefinitionEntry>,
+}
+
+/// モンスター定義アセット（テンプレートと継承を解決済み）
+#[derive(Asset, TypePath, Debug, Clone)]
 pub struct MonsterDefinitionsAsset {
     pub definitions: Vec<MonsterDefinition>,
 }
@@ -92,7 +149,53 @@ impl AssetLoader for MonsterDefinitionsAssetLoader {
         let content = std::str::from_utf8(&bytes)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
-        let asset: MonsterDefinitionsAsset = ron::from_str(content)
+        // Option型のフィールドをSome(...)で囲まずに書けるようにする
+        let file: MonsterDefinitionsFile = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(content)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        // テンプレートと継承を解決（親が見つからない・循環している場合はロードエラー）
+        let definitions = resolve_monster_definitions(&file.templates, &file.definitions)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        Ok(MonsterDefinitionsAsset { definitions })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// 障害物カタログアセット（obstacles.ron）
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct ObstacleCatalogAsset {
+    pub obstacles: Vec<ObstacleCatalogEntry>,
+}
+
+#[derive(Default)]
+pub struct ObstacleCatalogAssetLoader;
+
+impl AssetLoader for ObstacleCatalogAssetLoader {
+    type Asset = ObstacleCatalogAsset;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let content = std::str::from_utf8(&bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        // Option型のフィールドをSome(...)で囲まずに書けるようにする
+        let asset: ObstacleCatalogAsset = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(content)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
         Ok(asset)
@@ -110,6 +213,8 @@ impl Plugin for StageAssetPlugin {
         app.init_asset::<StageLevelAsset>()
             .init_asset_loader::<StageLevelAssetLoader>()
             .init_asset::<MonsterDefinitionsAsset>()
-            .init_asset_loader::<MonsterDefinitionsAssetLoader>();
+            .init_asset_loader::<MonsterDefinitionsAssetLoader>()
+            .init_asset::<ObstacleCatalogAsset>()
+            .init_asset_loader::<ObstacleCatalogAssetLoader>();
     }
 }
\ No newline at end of file
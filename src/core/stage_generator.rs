@@ -0,0 +1,303 @@
+use rand::prelude::*;
+use std::collections::HashSet;
+use crate::feature::monster::{MonsterKind, SpawnDefinition, WaveDefinition, WaveTrigger};
+use crate::feature::obstacle::{ObstacleDefinition, ObstacleKind};
+use super::config::{FIELD_WIDTH, FIELD_HEIGHT};
+use super::level;
+use super::rng::GameRng;
+use super::stage_asset::{StageLevelAsset, StageMode};
+use super::types::{Direction, GridPosition};
+
+/// 生成するステージのフィールドの大きさ（マス数）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSize {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Default for FieldSize {
+    /// ゲームのフィールド（config::FIELD_WIDTH/FIELD_HEIGHT）と同じ大きさ
+    fn default() -> Self {
+        Self {
+            width: FIELD_WIDTH,
+            height: FIELD_HEIGHT,
+        }
+    }
+}
+
+/// シードと難易度（0.0～1.0）から、指定した大きさのフィールド用のステージを生成する
+///
+/// 難易度が上がるほど使用するレーン・Wave数・モンスター数・種類・障害物が増え、
+/// スポーン間隔とWave間の休憩が短くなる。同じシード・難易度・フィールドの大きさなら常に同じステージになる。
+/// レーンはフィールド中央から難易度に合わせて広げ、レーンと障害物はすべてフィールドの中に収まる。
+/// 生成したステージは以下を満たす:
+/// - 障害物はモンスターが進入するレーンの入口のマスに置かれない
+/// - 各Waveの正面衝突（同じレーンを逆向きに進む組）は難易度ごとの上限以下
+pub fn generate_stage(seed: u64, difficulty: f32, field: FieldSize) -> StageLevelAsset {
+    let difficulty = difficulty.clamp(0.0, 1.0);
+    let mut rng = GameRng::new(seed);
+
+    // 使用するレーン（フィールド中央から広げる）
+    let lane_count = lerp_count(level::GENERATOR_LANE_COUNT_RANGE, difficulty) as i32;
+    let columns = centered_lanes(lane_count, field.width);
+    let rows = centered_lanes(lane_count, field.height);
+
+    // 出現するモンスターの種類（難易度が上がるほど増える）
+    let kind_count = 1 + ((MonsterKind::ALL.len() - 1) as f32 * difficulty).round() as usize;
+    let kinds = &MonsterKind::ALL[..kind_count];
+
+    let wave_count = lerp_count(level::GENERATOR_WAVE_COUNT_RANGE, difficulty);
+    let monster_count = lerp_count(level::GENERATOR_MONSTERS_PER_WAVE_RANGE, difficulty);
+    let interval = lerp(level::GENERATOR_SPAWN_INTERVAL_RANGE, difficulty);
+    let wave_break = lerp(level::GENERATOR_WAVE_BREAK_RANGE, difficulty);
+    let head_on_limit = lerp_count(level::GENERATOR_HEAD_ON_LIMIT_RANGE, difficulty);
+
+    let mut waves = Vec::new();
+    let mut start_time = 0.0;
+    for wave_index in 0..wave_count {
+        // Waveが進むごとに1体ずつ増やす
+        let monsters = generate_wave_monsters(
+            monster_count + wave_index,
+            interval,
+            kinds,
+            (&columns, &rows),
+            head_on_limit,
+            &mut rng,
+        );
+        let duration = monsters.last().map(|spawn_def| spawn_def.delay).unwrap_or(0.0);
+
+        waves.push(WaveDefinition {
+            start_time,
+            trigger: WaveTrigger::AtTime,
+            monsters,
+            random_spawns: Vec::new(),
+            patterns: Vec::new(),
+            obstacle_events: Vec::new(),
+        });
+        start_time = round_time(start_time + duration + wave_break);
+    }
+
+    // レーンの入口をふさがないように障害物を置く
+    let entry_cells: HashSet<GridPosition> = waves
+        .iter()
+        .flat_map(|wave| &wave.monsters)
+        .filter_map(|spawn_def| lane_entry_cell(spawn_def.direction, spawn_def.grid_pos, field))
+        .collect();
+    let obstacle_count = (level::GENERATOR_MAX_OBSTACLES as f32 * difficulty).round() as usize;
+    let obstacles = place_obstacles(obstacle_count, field, &entry_cells, &mut rng);
+
+    StageLevelAsset {
+        stage: 0,
+        level: 0,
+        seed: Some(seed),
+        mode: StageMode::Fixed,
+        spawn_telegraph_look_ahead: None,
+        diagonal_movement: false,
+        waves,
+        obstacles,
+        tides: Vec::new(),
+    }
+}
+
+/// Wave内の正面衝突（同じレーンを逆向きに進む組がいるレーン）の数
+pub fn count_head_on_conflicts(wave: &WaveDefinition) -> usize {
+    let used: HashSet<(Direction, i32)> = wave
+        .monsters
+        .iter()
+        .map(|spawn_def| (spawn_def.direction, spawn_def.grid_pos))
+        .collect();
+
+    used.iter()
+        .filter(|(direction, grid_pos)| {
+            matches!(direction, Direction::Right | Direction::Up) && used.contains(&(direction.opposite(), *grid_pos))
+        })
+        .count()
+}
+
+/// 1Wave分のスポーン定義を生成する
+///
+/// 正面衝突が上限に達している場合は、逆向きの流れがあるレーンでは同じ向きに合流させる
+fn generate_wave_monsters(
+    count: usize,
+    interval: f32,
+    kinds: &[MonsterKind],
+    (columns, rows): (&[i32], &[i32]),
+    head_on_limit: usize,
+    rng: &mut impl Rng,
+) -> Vec<SpawnDefinition> {
+    let directions = [Direction::Right, Direction::Left, Direction::Up, Direction::Down];
+    let mut used: HashSet<(Direction, i32)> = HashSet::new();
+    let mut conflicts = 0;
+    let mut monsters = Vec::new();
+
+    for index in 0..count {
+        let (Some(&kind), Some(&direction)) = (kinds.choose(rng), directions.choose(rng)) else {
+            continue;
+        };
+        let lanes = match direction {
+            Direction::Right | Direction::Left => rows,
+            _ => columns,
+        };
+        let Some(&grid_pos) = lanes.choose(rng) else {
+            continue;
+        };
+
+        let mut direction = direction;
+        let creates_conflict =
+            used.contains(&(direction.opposite(), grid_pos)) && !used.contains(&(direction, grid_pos));
+        if creates_conflict {
+            if conflicts >= head_on_limit {
+                direction = direction.opposite();
+            } else {
+                conflicts += 1;
+            }
+        }
+
+        used.insert((direction, grid_pos));
+        monsters.push(SpawnDefinition::new(kind, direction, grid_pos, round_time(index as f32 * interval)));
+    }
+
+    monsters
+}
+
+/// 障害物を置く（既に置いたマスとレーンの入口は避ける）
+fn place_obstacles(
+    count: usize,
+    field: FieldSize,
+    entry_cells: &HashSet<GridPosition>,
+    rng: &mut impl Rng,
+) -> Vec<ObstacleDefinition> {
+    let mut candidates: Vec<GridPosition> = (0..field.width)
+        .flat_map(|x| (0..field.height).map(move |y| GridPosition::new(x, y)))
+        .filter(|cell| !entry_cells.contains(cell))
+        .collect();
+    candidates.shuffle(rng);
+
+    candidates
+        .into_iter()
+        .take(count)
+        .filter_map(|grid_pos| {
+            let kind = *ObstacleKind::PROCEDURAL.choose(rng)?;
+            Some(ObstacleDefinition::new(kind, grid_pos))
+        })
+        .collect()
+}
+
+/// レーンでモンスターが最初に進入するマス（上下左右の方向のみ）
+fn lane_entry_cell(direction: Direction, grid_pos: i32, field: FieldSize) -> Option<GridPosition> {
+    match direction {
+        Direction::Right => Some(GridPosition::new(0, grid_pos)),
+        Direction::Left => Some(GridPosition::new(field.width - 1, grid_pos)),
+        Direction::Up => Some(GridPosition::new(grid_pos, 0)),
+        Direction::Down => Some(GridPosition::new(grid_pos, field.height - 1)),
+        _ => None,
+    }
+}
+
+/// フィールド中央に寄せたcount本のレーン
+fn centered_lanes(count: i32, field_size: i32) -> Vec<i32> {
+    let count = count.clamp(1, field_size);
+    let start = (field_size - count) / 2;
+    (start..start + count).collect()
+}
+
+/// 時間を0.01秒単位に丸める（書き出したRONを読みやすくする）
+fn round_time(seconds: f32) -> f32 {
+    (seconds * 100.0).round() / 100.0
+}
+
+/// 難易度0と難易度1の値の間を補間する
+fn lerp((easy, hard): (f32, f32), difficulty: f32) -> f32 {
+    easy + (hard - easy) * difficulty
+}
+
+/// 難易度0と難易度1の個数の間を補間する
+fn lerp_count((easy, hard): (usize, usize), difficulty: f32) -> usize {
+    lerp((easy as f32, hard as f32), difficulty).round() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEEDS: std::ops::Range<u64> = 0..50;
+    const DIFFICULTIES: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
+
+    #[test]
+    fn obstacles_never_block_lane_entries() {
+        for seed in SEEDS {
+            for difficulty in DIFFICULTIES {
+                let stage = generate_stage(seed, difficulty, FieldSize::default());
+                let entry_cells: HashSet<GridPosition> = stage
+                    .waves
+                    .iter()
+                    .flat_map(|wave| &wave.monsters)
+                    .filter_map(|spawn_def| lane_entry_cell(spawn_def.direction, spawn_def.grid_pos, FieldSize::default()))
+                    .collect();
+
+                for obstacle in &stage.obstacles {
+                    for cell in obstacle.cells() {
+                        assert!(
+                            !entry_cells.contains(&cell),
+                            "seed {} difficulty {}: {:?} blocks a lane entry at {:?}",
+                            seed, difficulty, obstacle.kind, cell
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn head_on_conflicts_stay_within_limit() {
+        for seed in SEEDS {
+            for difficulty in DIFFICULTIES {
+                let limit = lerp_count(level::GENERATOR_HEAD_ON_LIMIT_RANGE, difficulty);
+                let stage = generate_stage(seed, difficulty, FieldSize::default());
+
+                for (index, wave) in stage.waves.iter().enumerate() {
+                    let conflicts = count_head_on_conflicts(wave);
+                    assert!(
+                        conflicts <= limit,
+                        "seed {} difficulty {} wave {}: {} head-on lanes (limit {})",
+                        seed, difficulty, index, conflicts, limit
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_and_difficulty_generate_same_stage() {
+        let first = generate_stage(42, 0.6, FieldSize::default()).to_ron_string().unwrap();
+        let second = generate_stage(42, 0.6, FieldSize::default()).to_ron_string().unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn lanes_and_obstacles_fit_the_given_field() {
+        let field = FieldSize { width: 4, height: 3 };
+        for seed in SEEDS {
+            let stage = generate_stage(seed, 1.0, field);
+            for spawn_def in stage.waves.iter().flat_map(|wave| &wave.monsters) {
+                let lane_count = match spawn_def.direction {
+                    Direction::Right | Direction::Left => field.height,
+                    _ => field.width,
+                };
+                assert!((0..lane_count).contains(&spawn_def.grid_pos), "seed {}: {:?}", seed, spawn_def);
+            }
+            for cell in stage.obstacles.iter().flat_map(|obstacle| obstacle.cells()) {
+                assert!((0..field.width).contains(&cell.x) && (0..field.height).contains(&cell.y), "seed {}: {:?}", seed, cell);
+            }
+        }
+    }
+
+    #[test]
+    fn ron_output_loads_back_as_the_same_stage() {
+        for difficulty in DIFFICULTIES {
+            let content = generate_stage(7, difficulty, FieldSize::default()).to_ron_string().unwrap();
+            let loaded = StageLevelAsset::from_ron_str(&content).unwrap();
+            assert_eq!(loaded.to_ron_string().unwrap(), content);
+        }
+    }
+}
@@ -15,33 +15,81 @@ impl GridPosition {
 }
 
 /// モンスターの進行方向
+///
+/// 上下左右の4方向に加え、斜め4方向（フィールドの角から進入する）を持つ
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Direction {
     Up,
     Down,
     Left,
     Right,
+    UpRight,
+    UpLeft,
+    DownRight,
+    DownLeft,
 }
 
 impl Direction {
-    /// 方向を単位ベクトルに変換
+    /// 時計回りに並べた全方向（45度刻み）
+    const CLOCKWISE: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// 方向を単位ベクトルに変換（斜め方向も長さ1に正規化）
     pub fn to_vector(self) -> Vec2 {
+        use std::f32::consts::FRAC_1_SQRT_2;
+
         match self {
             Direction::Up => Vec2::new(0.0, 1.0),
             Direction::Down => Vec2::new(0.0, -1.0),
             Direction::Left => Vec2::new(-1.0, 0.0),
             Direction::Right => Vec2::new(1.0, 0.0),
+            Direction::UpRight => Vec2::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+            Direction::UpLeft => Vec2::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+            Direction::DownRight => Vec2::new(FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+            Direction::DownLeft => Vec2::new(-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
         }
     }
 
+    /// 斜め方向かどうか
+    pub fn is_diagonal(self) -> bool {
+        matches!(
+            self,
+            Direction::UpRight | Direction::UpLeft | Direction::DownRight | Direction::DownLeft
+        )
+    }
+
+    /// 時計回りにsteps × 45度回転
+    fn rotate_steps(self, steps: usize) -> Self {
+        let index = Self::CLOCKWISE.iter().position(|d| *d == self).unwrap_or(0);
+        Self::CLOCKWISE[(index + steps) % Self::CLOCKWISE.len()]
+    }
+
     /// 方向を90度右回転
     pub fn rotate_clockwise(self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
+        self.rotate_steps(2)
+    }
+
+    /// 方向を45度右回転
+    pub fn rotate_clockwise_45(self) -> Self {
+        self.rotate_steps(1)
+    }
+
+    /// 方向を45度左回転
+    pub fn rotate_counter_clockwise_45(self) -> Self {
+        self.rotate_steps(7)
+    }
+
+    /// 逆方向を取得
+    pub fn opposite(self) -> Self {
+        self.rotate_steps(4)
     }
 }
 
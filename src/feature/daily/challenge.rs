@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::fmt;
+use crate::core::{GameRng, StageLevelAsset, StageMode};
+use crate::core::level;
+use crate::feature::endless::{EndlessModifiers, EndlessState, pick_obstacle_cell};
+use crate::feature::monster::StageLevelLoader;
+use crate::feature::obstacle::{spawn_obstacle, ObstacleCatalog, ObstacleCatalogLoader, ObstacleDefinition, ObstacleKind};
+
+/// デイリーチャレンジの日付（UTC）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChallengeDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl ChallengeDate {
+    /// 1970-01-01からの経過日数から日付を求める
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // グレゴリオ暦への変換（400年周期の紀元を3月始まりで数える）
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+
+        Self { year, month, day }
+    }
+
+    /// 今日の日付（UTC）
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn today() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        Self::from_days_since_epoch((seconds / 86_400) as i64)
+    }
+
+    /// 今日の日付（UTC）
+    #[cfg(target_arch = "wasm32")]
+    pub fn today() -> Self {
+        // WASMではSystemTimeが使えないので、ブラウザの時計を使う
+        let milliseconds = js_sys::Date::now();
+        Self::from_days_since_epoch((milliseconds / 86_400_000.0).floor() as i64)
+    }
+
+    /// 日付から決まる乱数シード（例: 2025-11-01なら20251101）
+    pub fn seed(&self) -> u64 {
+        self.year as u64 * 10_000 + self.month as u64 * 100 + self.day as u64
+    }
+}
+
+impl fmt::Display for ChallengeDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// 今日のデイリーチャレンジ（デイリーチャレンジのステージでのみ存在する）
+///
+/// 同じ日付なら誰が遊んでも同じシード・変化要素・Waveの流れになる
+#[derive(Resource, Debug, Clone)]
+pub struct DailyChallenge {
+    pub date: ChallengeDate,
+    pub seed: u64,
+    pub modifiers: EndlessModifiers,
+    /// 開始時にフィールドに置かれる障害物
+    pub obstacles: Vec<ObstacleDefinition>,
+}
+
+impl DailyChallenge {
+    /// 日付からデイリーチャレンジを生成する
+    ///
+    /// 変化要素と障害物は、プレイ中に消費される乱数列とは別の、日付のシードから作った乱数で抽選する
+    pub fn generate(date: ChallengeDate) -> Self {
+        // Waveの生成（1から数える）と重ならないように、0番の乱数を使う
+        let rng = &mut GameRng::for_wave(date.seed(), 0);
+
+        let (speed_min, speed_max) = level::DAILY_SPEED_MULTIPLIER_RANGE;
+        let (interval_min, interval_max) = level::DAILY_SPAWN_INTERVAL_MULTIPLIER_RANGE;
+        let (wait_min, wait_max) = level::DAILY_WAIT_THRESHOLD_MULTIPLIER_RANGE;
+
+        let modifiers = EndlessModifiers {
+            speed_multiplier: rng.gen_range(speed_min..=speed_max),
+            spawn_interval_multiplier: rng.gen_range(interval_min..=interval_max),
+            wait_threshold_multiplier: rng.gen_range(wait_min..=wait_max),
+        };
+
+        let mut obstacles: Vec<ObstacleDefinition> = Vec::new();
+        for _ in 0..rng.gen_range(0..=level::DAILY_MAX_STARTING_OBSTACLES) {
+            let occupied: Vec<_> = obstacles.iter().flat_map(|obstacle| obstacle.cells()).collect();
+            let kind = ObstacleKind::PROCEDURAL.choose(rng).copied();
+            if let (Some(kind), Some(grid_pos)) = (kind, pick_obstacle_cell(&occupied, rng)) {
+                obstacles.push(ObstacleDefinition::new(kind, grid_pos));
+            }
+        }
+
+        Self {
+            date,
+            seed: date.seed(),
+            modifiers,
+            obstacles,
+        }
+    }
+}
+
+/// ステージがデイリーチャレンジなら今日のチャレンジを開始するシステム
+#[allow(clippy::too_many_arguments)]
+pub fn start_daily_challenge_system(
+    mut commands: Commands,
+    stage_loader: Res<StageLevelLoader>,
+    stage_assets: Res<Assets<StageLevelAsset>>,
+    catalog: Res<ObstacleCatalog>,
+    catalog_loader: Option<Res<ObstacleCatalogLoader>>,
+    asset_server: Res<AssetServer>,
+    mut rng: ResMut<GameRng>,
+    mut checked: Local<bool>,
+) {
+    // 開始時に障害物を置くので、障害物カタログのロードも待つ
+    if *checked || !stage_loader.loaded || !catalog_loader.is_some_and(|loader| loader.loaded) {
+        return;
+    }
+
+    let Some(stage_asset) = stage_assets.get(&stage_loader.handle) else {
+        return;
+    };
+    *checked = true;
+
+    if stage_asset.mode != StageMode::Daily {
+        return;
+    }
+
+    // ステージのシードの代わりに日付から決まるシードを使う
+    // （Waveの生成はEndlessStateのシードからWaveごとの乱数を作るので、ここでの再設定は風などプレイ中の乱数のみに効く）
+    let date = ChallengeDate::today();
+    rng.reseed(date.seed());
+    let challenge = DailyChallenge::generate(date);
+
+    for obstacle_def in &challenge.obstacles {
+        spawn_obstacle(&mut commands, obstacle_def, &catalog, &asset_server);
+    }
+
+    info!(
+        "Daily challenge {} started (seed {}): {:?}, {} obstacles",
+        challenge.date,
+        challenge.seed,
+        challenge.modifiers,
+        challenge.obstacles.len()
+    );
+
+    commands.insert_resource(EndlessState::new(challenge.seed, challenge.modifiers));
+    commands.insert_resource(challenge);
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::core::config::DAILY_LEADERBOARD_PATH;
+use crate::feature::endless::EndlessState;
+use super::challenge::DailyChallenge;
+
+/// 1日分のデイリーチャレンジの記録
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyRecord {
+    pub seed: u64,
+    pub best_score: u32,
+    pub best_waves_survived: u32,
+    pub best_monsters_crossed: u32,
+    pub plays: u32,
+}
+
+/// ローカルのデイリーチャレンジの記録（日付をキーにしたRONファイル）
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyLeaderboard {
+    pub records: BTreeMap<String, DailyRecord>,
+}
+
+impl DailyLeaderboard {
+    /// 記録ファイルを読み込む（ファイルがない・読めない場合は空の記録）
+    pub fn load() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match std::fs::read_to_string(DAILY_LEADERBOARD_PATH) {
+                Ok(content) => ron::from_str(&content).unwrap_or_else(|e| {
+                    warn!("Failed to parse {}: {}", DAILY_LEADERBOARD_PATH, e);
+                    Self::default()
+                }),
+                Err(_) => Self::default(),
+            }
+        }
+
+        // WASMではファイルに保存できないので、起動中だけ記録を保持する
+        #[cfg(target_arch = "wasm32")]
+        Self::default()
+    }
+
+    /// 記録ファイルに書き込む
+    pub fn save(&self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let result = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+                .map_err(|e| e.to_string())
+                .and_then(|content| std::fs::write(DAILY_LEADERBOARD_PATH, content).map_err(|e| e.to_string()));
+
+            if let Err(e) = result {
+                warn!("Failed to save {}: {}", DAILY_LEADERBOARD_PATH, e);
+            }
+        }
+    }
+
+    /// 指定した日付の記録
+    pub fn record(&self, date: &str) -> Option<&DailyRecord> {
+        self.records.get(date)
+    }
+
+    /// 結果を記録する（自己ベストを更新したらtrue）
+    pub fn submit(&mut self, date: &str, seed: u64, endless_state: &EndlessState) -> bool {
+        let record = self.records.entry(date.to_string()).or_default();
+        record.seed = seed;
+        record.plays += 1;
+
+        let is_best = record.plays == 1 || endless_state.score() > record.best_score;
+        if is_best {
+            record.best_score = endless_state.score();
+            record.best_waves_survived = endless_state.waves_survived;
+            record.best_monsters_crossed = endless_state.monsters_crossed;
+        }
+        is_best
+    }
+}
+
+/// デイリーチャレンジの最新の結果（リザルト画面に表示する）
+#[derive(Resource, Debug, Clone)]
+pub struct DailyResult {
+    pub date: String,
+    pub score: u32,
+    pub best_score: u32,
+    pub is_new_best: bool,
+}
+
+/// ゲームオーバー時にデイリーチャレンジの結果を記録ファイルに保存するシステム
+pub fn record_daily_result_system(
+    mut commands: Commands,
+    challenge: Res<DailyChallenge>,
+    endless_state: Res<EndlessState>,
+    mut leaderboard: ResMut<DailyLeaderboard>,
+) {
+    let date = challenge.date.to_string();
+    let is_new_best = leaderboard.submit(&date, challenge.seed, &endless_state);
+    leaderboard.save();
+
+    let best_score = leaderboard
+        .record(&date)
+        .map(|record| record.best_score)
+        .unwrap_or_default();
+
+    info!(
+        "Daily challenge {} result: score {} (best {}{})",
+        date,
+        endless_state.score(),
+        best_score,
+        if is_new_best { ", new best" } else { "" }
+    );
+
+    commands.insert_resource(DailyResult {
+        date,
+        score: endless_state.score(),
+        best_score,
+        is_new_best,
+    });
+}
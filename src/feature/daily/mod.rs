@@ -0,0 +1,7 @@
+mod challenge;
+mod leaderboard;
+mod plugin;
+
+pub use challenge::*;
+pub use leaderboard::*;
+pub use plugin::DailyPlugin;
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use crate::GameState;
+use super::challenge::*;
+use super::leaderboard::*;
+use crate::feature::ui::setup_daily_result_ui_system;
+
+/// デイリーチャレンジを提供するプラグイン
+///
+/// ステージファイルでmode: Dailyが指定されたときだけ動作し、Waveの生成はEndlessPluginに任せる
+pub struct DailyPlugin;
+
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(DailyLeaderboard::load())
+            .add_systems(Update, start_daily_challenge_system)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (record_daily_result_system, setup_daily_result_ui_system)
+                    .chain()
+                    .run_if(resource_exists::<DailyChallenge>)
+            );
+    }
+}
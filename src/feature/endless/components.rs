@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+/// エンドレスモードの進行状況（エンドレスモードのステージでのみ存在する）
+#[derive(Resource, Debug, Clone)]
+pub struct EndlessState {
+    /// Waveと障害物の生成に使うシード
+    pub seed: u64,
+    /// 生成するWaveに掛かる変化要素
+    pub modifiers: EndlessModifiers,
+    /// 生成済みのWave数
+    pub waves_generated: u32,
+    /// モンスターがすべていなくなったWave数
+    pub waves_survived: u32,
+    /// ゴールに到達したモンスター数
+    pub monsters_crossed: u32,
+}
+
+/// エンドレスモードの変化要素（デイリーチャレンジで日替わりに変わる）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EndlessModifiers {
+    /// モンスター速度の倍率
+    pub speed_multiplier: f32,
+    /// スポーン間隔の倍率
+    pub spawn_interval_multiplier: f32,
+    /// 待機時間閾値の倍率
+    pub wait_threshold_multiplier: f32,
+}
+
+impl Default for EndlessModifiers {
+    fn default() -> Self {
+        Self {
+            speed_multiplier: 1.0,
+            spawn_interval_multiplier: 1.0,
+            wait_threshold_multiplier: 1.0,
+        }
+    }
+}
+
+impl EndlessState {
+    pub fn new(seed: u64, modifiers: EndlessModifiers) -> Self {
+        Self {
+            seed,
+            modifiers,
+            waves_generated: 0,
+            waves_survived: 0,
+            monsters_crossed: 0,
+        }
+    }
+
+    /// スコア（生き残ったWave数 + ゴールに到達したモンスター数）
+    pub fn score(&self) -> u32 {
+        self.waves_survived + self.monsters_crossed
+    }
+}
@@ -0,0 +1,108 @@
+use rand::prelude::*;
+use crate::core::{Direction, GridPosition, FIELD_WIDTH, FIELD_HEIGHT};
+use crate::core::level;
+use crate::feature::monster::{MonsterDefinitions, MonsterKind, SpawnDefinition, WaveDefinition, WaveTrigger};
+use super::components::EndlessModifiers;
+
+/// エンドレスモードのWaveを生成する
+///
+/// wave_numberは1から始まり、Waveが進むごとにモンスター数・種類・速度が増え、スポーン間隔が短くなる
+pub fn generate_endless_wave(
+    wave_number: u32,
+    modifiers: &EndlessModifiers,
+    monster_defs: &MonsterDefinitions,
+    rng: &mut impl Rng,
+) -> WaveDefinition {
+    let step = wave_number.saturating_sub(1);
+    let count = level::ENDLESS_BASE_MONSTER_COUNT + step as usize * level::ENDLESS_MONSTERS_PER_WAVE;
+    let speed_multiplier = (1.0 + step as f32 * level::ENDLESS_SPEED_GROWTH)
+        .min(level::ENDLESS_MAX_SPEED_MULTIPLIER)
+        * modifiers.speed_multiplier;
+    let interval = (level::ENDLESS_BASE_SPAWN_INTERVAL * level::ENDLESS_SPAWN_INTERVAL_DECAY.powi(step as i32))
+        .max(level::ENDLESS_MIN_SPAWN_INTERVAL)
+        * modifiers.spawn_interval_multiplier;
+
+    // 一定のWaveごとに出現する種類を増やす
+    let unlocked = (1 + (step / level::ENDLESS_WAVES_PER_NEW_KIND) as usize).min(MonsterKind::ALL.len());
+    let kinds = &MonsterKind::ALL[..unlocked];
+
+    let directions = [Direction::Right, Direction::Left, Direction::Up, Direction::Down];
+
+    let monsters = (0..count)
+        .filter_map(|index| {
+            let kind = *kinds.choose(rng)?;
+            let direction = *directions.choose(rng)?;
+            let lane_count = match direction {
+                Direction::Right | Direction::Left => FIELD_HEIGHT,
+                _ => FIELD_WIDTH,
+            };
+            let grid_pos = rng.gen_range(0..lane_count);
+
+            let definition = monster_defs.get(kind);
+            let mut spawn_def = SpawnDefinition::new(kind, direction, grid_pos, index as f32 * interval);
+            spawn_def.speed = Some(definition.speed * speed_multiplier);
+            spawn_def.wait_threshold = Some(definition.wait_threshold * modifiers.wait_threshold_multiplier);
+            Some(spawn_def)
+        })
+        .collect();
+
+    WaveDefinition {
+        start_time: 0.0,
+        trigger: WaveTrigger::DelayAfterPreviousCleared(level::ENDLESS_WAVE_BREAK),
+        monsters,
+        random_spawns: Vec::new(),
+        patterns: Vec::new(),
+        obstacle_events: Vec::new(),
+    }
+}
+
+/// 障害物を置くマスを選ぶ
+///
+/// レーンの進入マスをふさがないようにフィールドの外周は避け、既に障害物があるマスも避ける
+pub fn pick_obstacle_cell(occupied: &[GridPosition], rng: &mut impl Rng) -> Option<GridPosition> {
+    let candidates: Vec<GridPosition> = (1..FIELD_WIDTH - 1)
+        .flat_map(|x| (1..FIELD_HEIGHT - 1).map(move |y| GridPosition::new(x, y)))
+        .filter(|cell| !occupied.contains(cell))
+        .collect();
+
+    candidates.choose(rng).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GameRng;
+    use crate::feature::monster::{MonsterDefinition, SpecialBehavior};
+
+    fn monster_defs() -> MonsterDefinitions {
+        let mut defs = MonsterDefinitions::default();
+        for kind in MonsterKind::ALL {
+            defs.insert(MonsterDefinition {
+                kind,
+                speed: 100.0,
+                size: 0.6,
+                color: (1.0, 1.0, 1.0),
+                wait_threshold: 10.0,
+                special_behavior: SpecialBehavior::None,
+                texture_path: String::new(),
+                goal_sides: None,
+                spirit_reward: None,
+            });
+        }
+        defs
+    }
+
+    fn generate(seed: u64, wave_number: u32) -> String {
+        let mut rng = GameRng::for_wave(seed, wave_number);
+        let wave = generate_endless_wave(wave_number, &EndlessModifiers::default(), &monster_defs(), &mut rng);
+        format!("{:?}", wave.monsters)
+    }
+
+    #[test]
+    fn same_seed_generates_same_waves() {
+        for wave_number in 1..=10 {
+            assert_eq!(generate(7, wave_number), generate(7, wave_number));
+        }
+        assert_ne!(generate(7, 5), generate(8, 5));
+    }
+}
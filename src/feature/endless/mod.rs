@@ -0,0 +1,8 @@
+mod components;
+mod generator;
+mod waves;
+mod plugin;
+
+pub use components::*;
+pub use generator::{generate_endless_wave, pick_obstacle_cell};
+pub use plugin::EndlessPlugin;
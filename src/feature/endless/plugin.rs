@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use crate::GameState;
+use super::components::EndlessState;
+use super::waves::*;
+use crate::feature::ui::{setup_endless_score_ui_system, update_endless_score_ui_system};
+
+/// エンドレスモードを提供するプラグイン
+///
+/// ステージファイルでmode: Endlessが指定されたときだけ動作する
+pub struct EndlessPlugin;
+
+impl Plugin for EndlessPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, start_endless_mode_system)
+            // EndlessStateが作成されたらスコア表示を生成
+            .add_systems(
+                Update,
+                setup_endless_score_ui_system
+                    .run_if(resource_added::<EndlessState>)
+            )
+            .add_systems(
+                Update,
+                (
+                    generate_endless_waves_system,
+                    track_endless_score_system,
+                    update_endless_score_ui_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(resource_exists::<EndlessState>)
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                report_endless_score_system.run_if(resource_exists::<EndlessState>)
+            );
+    }
+}
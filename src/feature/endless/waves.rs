@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::core::{GameRng, StageLevelAsset, StageMode};
+use crate::core::level;
+use crate::feature::monster::{
+    DespawnCause, MonsterDefinitions, MonsterDefinitionsLoader, MonsterDespawnEvent, MonsterSpawnQueue, StageLevelLoader,
+};
+use crate::feature::obstacle::{
+    spawn_obstacle, ObstacleCatalog, ObstacleCatalogLoader, ObstacleDefinition, ObstacleKind, ObstaclePosition,
+};
+use super::components::EndlessState;
+use super::generator::{generate_endless_wave, pick_obstacle_cell};
+
+/// ステージがエンドレスモードならEndlessStateを作成するシステム
+pub fn start_endless_mode_system(
+    mut commands: Commands,
+    stage_loader: Res<StageLevelLoader>,
+    stage_assets: Res<Assets<StageLevelAsset>>,
+    endless_state: Option<Res<EndlessState>>,
+    mut checked: Local<bool>,
+) {
+    if *checked || endless_state.is_some() || !stage_loader.loaded {
+        return;
+    }
+
+    let Some(stage_asset) = stage_assets.get(&stage_loader.handle) else {
+        return;
+    };
+
+    if stage_asset.mode == StageMode::Endless {
+        let seed = stage_asset.seed.unwrap_or(level::DEFAULT_RNG_SEED);
+        commands.insert_resource(EndlessState::new(seed, default()));
+        info!("Endless mode started (seed {})", seed);
+    }
+    *checked = true;
+}
+
+/// 未開始のWaveがなくなったら次のWaveを生成するシステム
+///
+/// 一定のWaveごとにフィールドに障害物も追加する。
+/// 乱数はWave番号ごとに独立したものを使うので、同じシードなら遊び方に関係なく同じWaveの流れになる
+#[allow(clippy::too_many_arguments)]
+pub fn generate_endless_waves_system(
+    mut commands: Commands,
+    mut endless_state: ResMut<EndlessState>,
+    spawn_queue: Option<ResMut<MonsterSpawnQueue>>,
+    monster_defs: Res<MonsterDefinitions>,
+    monster_defs_loader: Option<Res<MonsterDefinitionsLoader>>,
+    catalog: Res<ObstacleCatalog>,
+    catalog_loader: Option<Res<ObstacleCatalogLoader>>,
+    asset_server: Res<AssetServer>,
+    obstacle_query: Query<&ObstaclePosition>,
+) {
+    let Some(mut spawn_queue) = spawn_queue else {
+        return;
+    };
+
+    // モンスター定義と障害物カタログが読み込まれるまでは生成できない
+    if !monster_defs_loader.is_some_and(|loader| loader.loaded) || !catalog_loader.is_some_and(|loader| loader.loaded) {
+        return;
+    }
+
+    if spawn_queue.next_pending_wave().is_some() {
+        return;
+    }
+
+    endless_state.waves_generated += 1;
+    let wave_number = endless_state.waves_generated;
+    let mut rng = GameRng::for_wave(endless_state.seed, wave_number);
+    let wave = generate_endless_wave(wave_number, &endless_state.modifiers, &monster_defs, &mut rng);
+    info!("Endless wave {} generated: {} monsters", wave_number, wave.monsters.len());
+    spawn_queue.push_wave(wave);
+
+    if wave_number.is_multiple_of(level::ENDLESS_OBSTACLE_WAVE_INTERVAL) {
+        let occupied: Vec<_> = obstacle_query.iter().flat_map(|position| position.cells.iter().copied()).collect();
+        let kind = ObstacleKind::PROCEDURAL.choose(&mut rng).copied();
+
+        if let (Some(kind), Some(grid_pos)) = (kind, pick_obstacle_cell(&occupied, &mut rng)) {
+            spawn_obstacle(&mut commands, &ObstacleDefinition::new(kind, grid_pos), &catalog, &asset_server);
+            info!("Endless obstacle added: {:?} at {:?}", kind, grid_pos);
+        }
+    }
+}
+
+/// 生き残ったWave数とゴールに到達したモンスター数を集計するシステム
+pub fn track_endless_score_system(
+    mut endless_state: ResMut<EndlessState>,
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    mut despawn_events: MessageReader<MonsterDespawnEvent>,
+) {
+    for event in despawn_events.read() {
+        if event.cause == DespawnCause::ReachedGoal {
+            endless_state.monsters_crossed += 1;
+        }
+    }
+
+    if let Some(spawn_queue) = spawn_queue {
+        endless_state.waves_survived = spawn_queue.wave_cleared_times.len() as u32;
+    }
+}
+
+/// ゲームオーバー時にエンドレスモードのスコアを記録するシステム
+pub fn report_endless_score_system(endless_state: Res<EndlessState>) {
+    info!(
+        "Endless mode result: score {} ({} waves survived, {} monsters crossed)",
+        endless_state.score(),
+        endless_state.waves_survived,
+        endless_state.monsters_crossed
+    );
+}
@@ -15,4 +15,28 @@ pub enum ItemKind {
 #[derive(Component)]
 pub struct RotationTile {
     pub grid_pos: GridPosition,
+    pub mode: RotationTileMode,
+}
+
+/// ぐるぐる床の動作モード
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RotationTileMode {
+    /// 当たり判定が床に触れている間だけ90度右に向き、離れたら本来の方向に戻る
+    #[default]
+    Overlap,
+    /// モンスターの中心が床の中心を通過した瞬間に90度右に曲がり、
+    /// 新しいレーンの軸上に位置を合わせて進行方向を恒久的に変える
+    Snap,
+}
+
+/// 次に設置するぐるぐる床の設定（リソース）
+#[derive(Resource, Debug, Default)]
+pub struct RotationTileSettings {
+    pub mode: RotationTileMode,
+}
+
+/// Snapモードの床で曲がった直後であることを示すマーカー（同じ床で連続して曲がらないようにする）
+#[derive(Component, Debug)]
+pub struct SnapTurned {
+    pub grid_pos: GridPosition,
 }
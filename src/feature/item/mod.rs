@@ -5,3 +5,4 @@ mod rotation_tile;
 
 pub use components::*;
 pub use plugin::ItemPlugin;
+pub(crate) use placement::get_mouse_world_position;
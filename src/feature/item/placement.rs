@@ -14,6 +14,7 @@ pub fn place_item_on_click(
     existing_items: Query<(Entity, &RotationTile), With<Item>>,
     // プレイヤーゲージ
     mut gauges: ResMut<PlayerGauges>,
+    settings: Res<RotationTileSettings>,
 ) {
     if mouse_button.just_pressed(MouseButton::Left) {
         // マウス座標をワールド座標に変換
@@ -48,12 +49,16 @@ pub fn place_item_on_click(
 
                 // アイテムをスポーン
                 let world_pos = grid_to_world(grid_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+                let color = match settings.mode {
+                    RotationTileMode::Overlap => ITEM_COLOR,
+                    RotationTileMode::Snap => ITEM_SNAP_COLOR,
+                };
                 commands.spawn((
                     Item,
                     ItemKind::RotationTile,
-                    RotationTile { grid_pos },
+                    RotationTile { grid_pos, mode: settings.mode },
                     Sprite {
-                        color: Color::srgb(ITEM_COLOR.0, ITEM_COLOR.1, ITEM_COLOR.2),
+                        color: Color::srgb(color.0, color.1, color.2),
                         custom_size: Some(Vec2::splat(GRID_SIZE * 0.8)),
                         ..default()
                     },
@@ -64,8 +69,22 @@ pub fn place_item_on_click(
     }
 }
 
+/// Mキーで次に設置するぐるぐる床のモードを切り替えるシステム
+pub fn toggle_rotation_tile_mode_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<RotationTileSettings>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        settings.mode = match settings.mode {
+            RotationTileMode::Overlap => RotationTileMode::Snap,
+            RotationTileMode::Snap => RotationTileMode::Overlap,
+        };
+        info!("ぐるぐる床のモード: {:?}", settings.mode);
+    }
+}
+
 /// マウスのワールド座標を取得
-fn get_mouse_world_position(
+pub(crate) fn get_mouse_world_position(
     windows: &Query<&Window, With<PrimaryWindow>>,
     camera_query: &Query<(&Camera, &GlobalTransform)>,
 ) -> Option<Vec2> {
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use super::components::RotationTileSettings;
 use super::placement::*;
 use super::rotation_tile::*;
 
@@ -7,12 +8,16 @@ pub struct ItemPlugin;
 impl Plugin for ItemPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<RotationTileSettings>()
             // システムの登録
             .add_systems(Update, (
+                // ぐるぐる床のモード切り替え
+                toggle_rotation_tile_mode_system,
                 // アイテム配置システム
                 place_item_on_click,
-                // ぐるぐる床の効果システム
-                rotation_tile_effect_system,
+                // ぐるぐる床の効果システム（速度を本来の値に戻すので、障害物の速度効果より前に実行）
+                rotation_tile_effect_system
+                    .before(crate::feature::obstacle::track_obstacle_occupancy_system),
             ));
     }
 }
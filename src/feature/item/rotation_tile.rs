@@ -1,25 +1,69 @@
 use bevy::prelude::*;
 use crate::core::{config::*, types::*};
-use crate::feature::monster::{Monster, MonsterProperty, Movement, CollisionBox};
+use crate::feature::monster::{
+    Monster, MonsterKind, MonsterProperty, Movement, CollisionBox, MonsterLifecycleEvent, LifecycleEvent, MovementMode,
+};
 use super::components::*;
 
 /// ぐるぐる床の効果を適用するシステム
-/// モンスターの当たり判定矩形が床のグリッドに触れている間だけ90度右に移動し、離れたら本来の方向に戻る
+///
+/// Overlapモード: モンスターの当たり判定矩形が床のグリッドに触れている間だけ90度右に移動し、離れたら本来の方向に戻る
+/// Snapモード: モンスターの中心が床の中心を通過したら90度右に曲がり、新しいレーンの軸上に位置を合わせて
+/// 本来の方向そのものを変更する
+///
+/// 斜め移動モードのステージでは90度ではなく45度ずつ曲げる（斜めのレーンにも床の中心を通る線に合わせる）
+#[allow(clippy::type_complexity)]
 pub fn rotation_tile_effect_system(
+    mut commands: Commands,
+    time: Res<Time>,
     tile_query: Query<&RotationTile, With<Item>>,
+    movement_mode: Res<MovementMode>,
     mut monster_query: Query<
-        (&Transform, &CollisionBox, &MonsterProperty, &mut Movement),
+        (Entity, &MonsterKind, &mut Transform, &CollisionBox, &mut MonsterProperty, &mut Movement, Option<&SnapTurned>),
         With<Monster>,
     >,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
 ) {
-    for (monster_transform, collision_box, property, mut movement) in monster_query.iter_mut() {
+    for (entity, kind, mut monster_transform, collision_box, mut property, mut movement, snap_turned) in monster_query.iter_mut() {
         let monster_pos = monster_transform.translation.xy();
+        let monster_grid_pos = world_to_grid(monster_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
         let half_size = collision_box.size / 2.0;
+        let previous_direction = movement.direction;
 
-        // モンスターの当たり判定矩形が触れているグリッドを判定
+        // 曲がった床のマスを出たらマーカーを外す（再び同じ床に来たら曲がれるようにする）
+        let turned_here = snap_turned.is_some_and(|marker| marker.grid_pos == monster_grid_pos);
+        if snap_turned.is_some() && !turned_here {
+            commands.entity(entity).remove::<SnapTurned>();
+        }
+
+        // Snapモードの床: 中心を通過した瞬間に曲がってレーンに合わせる
+        if !turned_here {
+            let snap_tile = tile_query
+                .iter()
+                .find(|tile| tile.mode == RotationTileMode::Snap && tile.grid_pos == monster_grid_pos);
+
+            if let Some(tile) = snap_tile {
+                let tile_world_pos = grid_to_world(tile.grid_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+                let overshoot = (monster_pos - tile_world_pos).dot(previous_direction.to_vector());
+
+                if overshoot >= 0.0 {
+                    let new_direction = movement_mode.turn(property.base_direction);
+                    property.base_direction = new_direction;
+
+                    // 床の中心から新しい進行方向に通り過ぎた分だけ進めた位置に合わせる
+                    let snapped = tile_world_pos + new_direction.to_vector() * overshoot;
+                    monster_transform.translation.x = snapped.x;
+                    monster_transform.translation.y = snapped.y;
+
+                    commands.entity(entity).insert(SnapTurned { grid_pos: tile.grid_pos });
+                }
+            }
+        }
+
+        // モンスターの当たり判定矩形が触れているOverlapモードの床を判定
         let mut on_rotation_tile = false;
 
-        for tile in tile_query.iter() {
+        for tile in tile_query.iter().filter(|tile| tile.mode == RotationTileMode::Overlap) {
             // タイルのグリッド座標をワールド座標に変換
             let tile_world_pos = grid_to_world(tile.grid_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
             let tile_half_size = GRID_SIZE / 2.0;
@@ -34,15 +78,28 @@ pub fn rotation_tile_effect_system(
             }
         }
 
-        // ぐるぐる床に触れている場合は90度右回転、それ以外は本来の方向
+        // ぐるぐる床に触れている場合は右回転、それ以外は本来の方向
         if on_rotation_tile {
-            // 本来の方向から90度右回転
-            movement.direction = property.base_direction.rotate_clockwise();
+            // 本来の方向から右回転（斜め移動モードでは45度）
+            movement.direction = movement_mode.turn(property.base_direction);
         } else {
             // 本来の方向に戻す
             movement.direction = property.base_direction;
         }
 
+        if movement.direction != previous_direction {
+            lifecycle_events.write(MonsterLifecycleEvent::new(
+                entity,
+                *kind,
+                monster_transform.translation.xy(),
+                time.elapsed_secs(),
+                LifecycleEvent::DirectionChanged {
+                    from: previous_direction,
+                    to: movement.direction,
+                },
+            ));
+        }
+
         // speedは常に本来の速度を使用
         movement.speed = property.base_speed;
     }
@@ -4,3 +4,5 @@ pub mod item;
 pub mod obstacle;
 pub mod player;
 pub mod ui;
+pub mod endless;
+pub mod daily;
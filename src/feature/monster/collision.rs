@@ -1,63 +1,129 @@
 use bevy::prelude::*;
 use super::components::*;
+use super::definitions::MonsterKind;
+use super::events::{MonsterLifecycleEvent, LifecycleEvent};
 use super::special_behavior::SpecialBehavior;
 use crate::core::GRID_SIZE;
 
 /// 衝突検知システム
-/// 次フレームの予測位置と現在の他モンスターの位置で矩形衝突判定を行う
+/// 実際に動く向き（コンベアなどの外部速度を含む）の予測位置と現在の他モンスター・静的コライダー（壁など）の位置で矩形衝突判定を行う
+#[allow(clippy::type_complexity)]
 pub fn collision_detection_system(
-    mut query: Query<(Entity, &Transform, &Movement, &CollisionBox, &mut CollisionState, &MonsterState, Option<&SpecialBehavior>), With<Monster>>,
+    time: Res<Time>,
+    mut query: Query<
+        (
+            Entity,
+            &MonsterKind,
+            &Transform,
+            &Movement,
+            Option<&ExternalVelocity>,
+            &CollisionBox,
+            &mut CollisionState,
+            &MonsterState,
+            Option<&SpecialBehavior>,
+        ),
+        With<Monster>
+    >,
+    static_query: Query<(Entity, &Transform, &StaticCollider), Without<Monster>>,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
 ) {
     // 全モンスターの位置情報を事前に収集（Cloneして所有権を持つ）
     let monsters: Vec<_> = query
         .iter()
-        .map(|(e, t, _, cb, _, s, sb)| (e, t.translation, cb.size, *s, sb.cloned()))
+        .map(|(e, _, t, _, _, cb, _, s, sb)| (e, t.translation, cb.size, *s, sb.cloned()))
         .collect();
 
     // 各モンスターについて衝突判定
-    for (entity, transform, movement, collision_box, mut collision_state, state, special_behavior) in &mut query {
-        // Moving状態のモンスターのみ衝突判定を行う
-        if *state != MonsterState::Moving {
-            collision_state.is_colliding = false;
-            continue;
-        }
+    for (entity, kind, transform, movement, external, collision_box, mut collision_state, state, special_behavior) in &mut query {
+        let was_colliding = collision_state.is_colliding;
+        collision_state.is_colliding = false;
+        collision_state.blocked_by = None;
 
+        // Moving状態のモンスターのみ衝突判定を行う
         // PassThrough挙動を持つモンスターは衝突判定をスキップ
-        if matches!(special_behavior, Some(SpecialBehavior::PassThrough)) {
-            collision_state.is_colliding = false;
-            continue;
-        }
+        let skip = *state != MonsterState::Moving
+            || matches!(special_behavior, Some(SpecialBehavior::PassThrough));
 
-        // 自分の予測位置を計算（0.1 * SIZE = 6.4ピクセル先）
-        let direction_vector = movement.direction.to_vector();
-        let check_distance = 0.1 * GRID_SIZE;
-        let predicted_pos = transform.translation + direction_vector.extend(0.0) * check_distance;
+        if !skip {
+            // 自分の予測位置を計算（実際に動く向きに0.1 * SIZE = 6.4ピクセル先）
+            // 止まっている場合は自分の進行方向で判定する
+            let direction_vector = movement
+                .velocity(external)
+                .try_normalize()
+                .unwrap_or_else(|| movement.direction.to_vector());
+            let check_distance = 0.1 * GRID_SIZE;
+            let predicted_pos = transform.translation + direction_vector.extend(0.0) * check_distance;
 
-        collision_state.is_colliding = false;
+            // 他のモンスターとの衝突をチェック
+            for (other_entity, other_pos, other_size, other_state, other_special_behavior) in &monsters {
+                // 自分自身はスキップ
+                if entity == *other_entity {
+                    continue;
+                }
 
-        // 他のモンスターとの衝突をチェック
-        for (other_entity, other_pos, other_size, other_state, other_special_behavior) in &monsters {
-            // 自分自身はスキップ
-            if entity == *other_entity {
-                continue;
-            }
+                // 相手もMoving状態でない場合はスキップ
+                if *other_state != MonsterState::Moving {
+                    continue;
+                }
 
-            // 相手もMoving状態でない場合はスキップ
-            if *other_state != MonsterState::Moving {
-                continue;
-            }
+                // 相手がPassThrough挙動を持つ場合は衝突判定をスキップ
+                if matches!(other_special_behavior.as_ref(), Some(SpecialBehavior::PassThrough)) {
+                    continue;
+                }
 
-            // 相手がPassThrough挙動を持つ場合は衝突判定をスキップ
-            if matches!(other_special_behavior.as_ref(), Some(SpecialBehavior::PassThrough)) {
-                continue;
+                // 予測位置と相手の現在位置で矩形衝突判定
+                if check_aabb_collision(predicted_pos, collision_box.size, *other_pos, *other_size) {
+                    collision_state.is_colliding = true;
+                    collision_state.blocked_by = Some(*other_entity);
+                    break;
+                }
             }
 
-            // 予測位置と相手の現在位置で矩形衝突判定
-            if check_aabb_collision(predicted_pos, collision_box.size, *other_pos, *other_size) {
-                collision_state.is_colliding = true;
-                break;
+            // 静的コライダー（壁など）との衝突をチェック
+            if !collision_state.is_colliding {
+                let blocker = static_query.iter().find(|(_, static_transform, collider)| {
+                    collider.offsets.iter().any(|offset| {
+                        let static_pos = static_transform.translation + offset.extend(0.0);
+                        if !check_aabb_collision(predicted_pos, collision_box.size, static_pos, collider.size) {
+                            return false;
+                        }
+
+                        // 一方通行: 矢印と逆向きの成分を持って外から進入しようとする場合だけ止める
+                        // （すでに中にいる場合は閉じ込めないように通す）
+                        match collider.one_way {
+                            Some(one_way) => {
+                                direction_vector.dot(one_way.to_vector()) < 0.0
+                                    && !check_aabb_collision(transform.translation, collision_box.size, static_pos, collider.size)
+                            }
+                            None => true,
+                        }
+                    })
+                });
+
+                if let Some((static_entity, _, _)) = blocker {
+                    collision_state.is_colliding = true;
+                    collision_state.blocked_by = Some(static_entity);
+                }
             }
         }
+
+        // 衝突状態の変化をライフサイクルイベントとして通知
+        let event = match (was_colliding, collision_state.is_colliding) {
+            (false, true) => Some(LifecycleEvent::CollisionStarted {
+                other: collision_state.blocked_by,
+            }),
+            (true, false) => Some(LifecycleEvent::CollisionEnded),
+            _ => None,
+        };
+        if let Some(event) = event {
+            lifecycle_events.write(MonsterLifecycleEvent::new(
+                entity,
+                *kind,
+                transform.translation.xy(),
+                time.elapsed_secs(),
+                event,
+            ));
+        }
     }
 }
 
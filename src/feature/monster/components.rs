@@ -40,6 +40,43 @@ impl MonsterProperty {
     }
 }
 
+/// 所属するWaveのインデックス（Waveが片付いたかの判定に使用）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WaveMember(pub usize);
+
+/// ゴール到達時に得られる魂の量
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpiritReward(pub f32);
+
+/// ゴール到達時に追加で得られるボーナス（祠のそばを通ったときなどに付与される）
+#[derive(Component, Debug, Clone, Default)]
+pub struct GoalBonuses(pub Vec<super::events::GoalBonus>);
+
+/// ゴールとなる出口の辺
+///
+/// このコンポーネントを持つモンスターは、指定された辺以外からフィールド外に出ると
+/// ゴール扱いにならない（Right = 右端から出る、UpRight = 右上の角から出る）
+#[derive(Component, Debug, Clone)]
+pub struct GoalSides(pub Vec<Direction>);
+
+impl GoalSides {
+    /// 指定した辺がゴールか
+    ///
+    /// 角から出た場合は、斜めの辺そのものか、角を作る上下左右の辺のどちらかが指定されていればゴール
+    pub fn accepts(&self, side: Direction) -> bool {
+        if self.0.contains(&side) {
+            return true;
+        }
+        if !side.is_diagonal() {
+            return false;
+        }
+        let vector = side.to_vector();
+        let horizontal = if vector.x > 0.0 { Direction::Right } else { Direction::Left };
+        let vertical = if vector.y > 0.0 { Direction::Up } else { Direction::Down };
+        self.0.contains(&horizontal) || self.0.contains(&vertical)
+    }
+}
+
 /// 移動情報（実際の移動に使用される、アイテムや環境の影響を受けた値）
 #[derive(Component, Debug, Clone, Copy)]
 pub struct Movement {
@@ -57,6 +94,16 @@ impl Movement {
             enabled: true,
         }
     }
+
+    /// 実際に動く速度（コンベアなどの外部速度は自分の移動に加算するか置き換える）
+    pub fn velocity(&self, external: Option<&ExternalVelocity>) -> Vec2 {
+        let own_velocity = self.direction.to_vector() * self.speed;
+        match external {
+            Some(external) if external.replace => external.velocity,
+            Some(external) => own_velocity + external.velocity,
+            None => own_velocity,
+        }
+    }
 }
 
 /// 待機タイマー
@@ -71,6 +118,29 @@ impl StagingTimer {
     }
 }
 
+/// 待機レーン（進入方向とレーン位置の組）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StagingLane {
+    pub direction: Direction,
+    pub grid_pos: i32,
+}
+
+impl StagingLane {
+    pub fn new(direction: Direction, grid_pos: i32) -> Self {
+        Self { direction, grid_pos }
+    }
+}
+
+/// 待機列での位置
+///
+/// index 0が進入地点で、待機タイマーが進むのはindex 0のモンスターのみ。
+/// 進入地点が空くと後ろのモンスターが1つずつ前に詰める
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StagingSlot {
+    pub lane: StagingLane,
+    pub index: usize,
+}
+
 /// 衝突判定用のボックス
 #[derive(Component, Debug, Clone, Copy)]
 pub struct CollisionBox {
@@ -83,15 +153,40 @@ impl CollisionBox {
     }
 }
 
+/// 外部から加わる速度（コンベアなどの障害物の上にいる間だけ付与される）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ExternalVelocity {
+    /// 速度（px/秒）
+    pub velocity: Vec2,
+    /// trueなら自分の移動を止めてこの速度だけで動く、falseなら自分の移動に加算する
+    pub replace: bool,
+}
+
+/// モンスターの進行を止める静的なコライダー（壁などの障害物に付ける）
+#[derive(Component, Debug, Clone)]
+pub struct StaticCollider {
+    /// 1つの箱の大きさ
+    pub size: Vec2,
+    /// 箱の中心の位置（Transformからの相対位置、複数のマスを覆う障害物は箱を並べる）
+    pub offsets: Vec<Vec2>,
+    /// 一方通行の向き（指定すると、この向きと逆向きの成分を持って進入するモンスターだけを止める）
+    pub one_way: Option<Direction>,
+}
+
 /// 衝突状態
 #[derive(Component, Debug, Default, Clone, Copy)]
 pub struct CollisionState {
     pub is_colliding: bool,
+    /// 進行を妨げている相手のエンティティ（衝突していない場合はNone）
+    pub blocked_by: Option<Entity>,
 }
 
 impl CollisionState {
     pub fn new() -> Self {
-        Self { is_colliding: false }
+        Self {
+            is_colliding: false,
+            blocked_by: None,
+        }
     }
 }
 
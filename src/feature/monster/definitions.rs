@@ -1,16 +1,22 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::core::Direction;
 use super::special_behavior::SpecialBehavior;
 
 /// モンスターの種類
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum MonsterKind {
     Kappa,      // 河童 - 標準的な速度と挙動
     Ghost,      // ゴースト - 高速移動
     Bakeneko,   // 化け猫 - 大型でゆっくり
 }
 
+impl MonsterKind {
+    /// すべてのモンスターの種類（宣言順、ステージ生成とエンドレスモードが出現させる種類の一覧）
+    pub const ALL: [MonsterKind; 3] = [MonsterKind::Kappa, MonsterKind::Ghost, MonsterKind::Bakeneko];
+}
+
 /// モンスターの定義（種類ごとのパラメータ）
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MonsterDefinition {
@@ -21,6 +27,157 @@ pub struct MonsterDefinition {
     pub wait_threshold: f32,
     pub special_behavior: SpecialBehavior,
     pub texture_path: String,  // テクスチャファイルのパス
+    /// ゴールとなる出口の辺（Noneならどの辺から出てもゴール）
+    #[serde(default)]
+    pub goal_sides: Option<Vec<Direction>>,
+    /// ゴール到達時の魂の増加量（Noneならlevel::SPIRIT_GAIN_PER_GOAL）
+    #[serde(default)]
+    pub spirit_reward: Option<f32>,
+}
+
+/// monsters.ronのエントリ（継承の解決前）
+///
+/// parentに指定したテンプレートまたはモンスター種類の値を引き継ぎ、
+/// 指定したフィールドだけを上書きする
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MonsterDefinitionEntry {
+    /// テンプレート名（templates内のエントリで使用）
+    #[serde(default)]
+    pub name: Option<String>,
+    /// モンスターの種類（definitions内のエントリで使用）
+    #[serde(default)]
+    pub kind: Option<MonsterKind>,
+    /// 親のテンプレート名またはモンスター種類名（例: "standard", "Kappa"）
+    #[serde(default)]
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub speed: Option<f32>,
+    #[serde(default)]
+    pub size: Option<f32>,
+    #[serde(default)]
+    pub color: Option<(f32, f32, f32)>,
+    #[serde(default)]
+    pub wait_threshold: Option<f32>,
+    /// 特殊挙動（省略すると親から引き継ぎ、`special_behavior: None`と書くと親の特殊挙動を外す）
+    #[serde(default, with = "super::special_behavior::behavior_override", skip_serializing_if = "Option::is_none")]
+    pub special_behavior: Option<SpecialBehavior>,
+    #[serde(default)]
+    pub texture_path: Option<String>,
+    #[serde(default)]
+    pub goal_sides: Option<Vec<Direction>>,
+    #[serde(default)]
+    pub spirit_reward: Option<f32>,
+}
+
+impl MonsterDefinitionEntry {
+    /// 自分で指定していないフィールドを親の値で埋める
+    fn inherit(&self, parent: &MonsterDefinitionEntry) -> MonsterDefinitionEntry {
+        MonsterDefinitionEntry {
+            name: self.name.clone(),
+            kind: self.kind,
+            parent: None,
+            speed: self.speed.or(parent.speed),
+            size: self.size.or(parent.size),
+            color: self.color.or(parent.color),
+            wait_threshold: self.wait_threshold.or(parent.wait_threshold),
+            special_behavior: self.special_behavior.clone().or_else(|| parent.special_behavior.clone()),
+            texture_path: self.texture_path.clone().or_else(|| parent.texture_path.clone()),
+            goal_sides: self.goal_sides.clone().or_else(|| parent.goal_sides.clone()),
+            spirit_reward: self.spirit_reward.or(parent.spirit_reward),
+        }
+    }
+
+    /// エラーメッセージ用のエントリ名
+    fn label(&self) -> String {
+        match (&self.kind, &self.name) {
+            (Some(kind), _) => format!("{:?}", kind),
+            (None, Some(name)) => name.clone(),
+            (None, None) => "<unnamed>".to_string(),
+        }
+    }
+}
+
+/// テンプレートと継承を解決してモンスター定義のリストを作成
+///
+/// 親が見つからない場合、継承が循環している場合、必須フィールドが埋まらない場合はエラーを返す
+pub fn resolve_monster_definitions(
+    templates: &[MonsterDefinitionEntry],
+    entries: &[MonsterDefinitionEntry],
+) -> Result<Vec<MonsterDefinition>, String> {
+    // 親として参照できるエントリを名前で引けるようにする
+    let mut by_name: HashMap<String, &MonsterDefinitionEntry> = HashMap::new();
+    for template in templates {
+        let Some(name) = &template.name else {
+            return Err("Monster template without name".to_string());
+        };
+        if by_name.insert(name.clone(), template).is_some() {
+            return Err(format!("Duplicate monster template '{}'", name));
+        }
+    }
+    for entry in entries {
+        let Some(kind) = entry.kind else {
+            return Err(format!("Monster definition '{}' has no kind", entry.label()));
+        };
+        if by_name.insert(format!("{:?}", kind), entry).is_some() {
+            return Err(format!("Duplicate monster definition or template named '{:?}'", kind));
+        }
+    }
+
+    entries
+        .iter()
+        .map(|entry| {
+            let resolved = resolve_entry(entry, &by_name, &mut Vec::new())?;
+            into_definition(&resolved)
+        })
+        .collect()
+}
+
+/// 親をたどって継承を解決する（chainは循環検出用の経路）
+fn resolve_entry(
+    entry: &MonsterDefinitionEntry,
+    by_name: &HashMap<String, &MonsterDefinitionEntry>,
+    chain: &mut Vec<String>,
+) -> Result<MonsterDefinitionEntry, String> {
+    let label = entry.label();
+    if chain.contains(&label) {
+        chain.push(label);
+        return Err(format!("Monster definition inheritance cycle: {}", chain.join(" -> ")));
+    }
+
+    let Some(parent_name) = &entry.parent else {
+        return Ok(entry.clone());
+    };
+
+    let Some(parent) = by_name.get(parent_name) else {
+        return Err(format!(
+            "Monster definition '{}' refers to missing parent '{}'",
+            label, parent_name
+        ));
+    };
+
+    chain.push(label);
+    let resolved_parent = resolve_entry(parent, by_name, chain)?;
+    chain.pop();
+
+    Ok(entry.inherit(&resolved_parent))
+}
+
+/// 継承解決済みのエントリをモンスター定義に変換
+fn into_definition(entry: &MonsterDefinitionEntry) -> Result<MonsterDefinition, String> {
+    let label = entry.label();
+    let missing = |field: &str| format!("Monster definition '{}' is missing '{}'", label, field);
+
+    Ok(MonsterDefinition {
+        kind: entry.kind.ok_or_else(|| missing("kind"))?,
+        speed: entry.speed.ok_or_else(|| missing("speed"))?,
+        size: entry.size.ok_or_else(|| missing("size"))?,
+        color: entry.color.ok_or_else(|| missing("color"))?,
+        wait_threshold: entry.wait_threshold.ok_or_else(|| missing("wait_threshold"))?,
+        special_behavior: entry.special_behavior.clone().unwrap_or(SpecialBehavior::None),
+        texture_path: entry.texture_path.clone().ok_or_else(|| missing("texture_path"))?,
+        goal_sides: entry.goal_sides.clone(),
+        spirit_reward: entry.spirit_reward,
+    })
 }
 
 /// モンスター定義を管理するリソース
@@ -54,3 +211,96 @@ pub struct MonsterDefinitionsLoader {
     pub handle: Handle<crate::core::MonsterDefinitionsAsset>,
     pub loaded: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(name: &str, parent: Option<&str>) -> MonsterDefinitionEntry {
+        MonsterDefinitionEntry {
+            name: Some(name.to_string()),
+            parent: parent.map(str::to_string),
+            ..default()
+        }
+    }
+
+    fn entry(kind: MonsterKind, parent: Option<&str>) -> MonsterDefinitionEntry {
+        MonsterDefinitionEntry {
+            kind: Some(kind),
+            parent: parent.map(str::to_string),
+            ..default()
+        }
+    }
+
+    fn complete(mut entry: MonsterDefinitionEntry) -> MonsterDefinitionEntry {
+        entry.speed = Some(100.0);
+        entry.size = Some(0.6);
+        entry.color = Some((1.0, 1.0, 1.0));
+        entry.wait_threshold = Some(10.0);
+        entry.texture_path = Some("img/kappa.png".to_string());
+        entry
+    }
+
+    #[test]
+    fn resolves_inheritance_chain() {
+        let base = MonsterDefinitionEntry {
+            special_behavior: Some(SpecialBehavior::PassThrough),
+            ..complete(template("base", None))
+        };
+        let fast = MonsterDefinitionEntry {
+            speed: Some(150.0),
+            ..template("fast", Some("base"))
+        };
+        let kappa = MonsterDefinitionEntry {
+            size: Some(0.8),
+            ..entry(MonsterKind::Kappa, Some("fast"))
+        };
+
+        let definitions = resolve_monster_definitions(&[base, fast], &[kappa]).unwrap();
+
+        assert_eq!(definitions.len(), 1);
+        let kappa = &definitions[0];
+        assert_eq!(kappa.kind, MonsterKind::Kappa);
+        assert_eq!(kappa.speed, 150.0);
+        assert_eq!(kappa.size, 0.8);
+        assert_eq!(kappa.wait_threshold, 10.0);
+        assert_eq!(kappa.special_behavior, SpecialBehavior::PassThrough);
+    }
+
+    #[test]
+    fn child_can_clear_inherited_special_behavior() {
+        let base = MonsterDefinitionEntry {
+            special_behavior: Some(SpecialBehavior::PassThrough),
+            ..complete(template("base", None))
+        };
+        let child: MonsterDefinitionEntry = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(r#"(kind: Kappa, parent: "base", special_behavior: None)"#)
+            .unwrap();
+
+        let definitions = resolve_monster_definitions(&[base], &[child]).unwrap();
+
+        assert_eq!(definitions[0].special_behavior, SpecialBehavior::None);
+    }
+
+    #[test]
+    fn reports_inheritance_cycle() {
+        let kappa = complete(entry(MonsterKind::Kappa, Some("Ghost")));
+        let ghost = complete(entry(MonsterKind::Ghost, Some("loop")));
+        let looping = template("loop", Some("Kappa"));
+
+        let error = resolve_monster_definitions(&[looping], &[kappa, ghost]).unwrap_err();
+
+        assert!(error.contains("inheritance cycle"), "{}", error);
+        assert!(error.contains("Kappa -> Ghost -> loop -> Kappa"), "{}", error);
+    }
+
+    #[test]
+    fn reports_missing_parent() {
+        let kappa = complete(entry(MonsterKind::Kappa, Some("unknown")));
+
+        let error = resolve_monster_definitions(&[], &[kappa]).unwrap_err();
+
+        assert!(error.contains("missing parent 'unknown'"), "{}", error);
+    }
+}
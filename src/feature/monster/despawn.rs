@@ -1,22 +1,55 @@
 use bevy::prelude::*;
 use super::components::*;
-use super::events::{MonsterDespawnEvent, DespawnCause};
+use super::definitions::MonsterKind;
+use super::events::{MonsterDespawnEvent, DespawnCause, MonsterLifecycleEvent, LifecycleEvent};
+use super::movement::exit_side;
 
 /// 到達したモンスターを消滅させるシステム
+///
+/// GoalSidesを持つモンスターが指定以外の辺から出た場合は誤った出口として扱う
+#[allow(clippy::type_complexity)]
 pub fn despawn_reached_monsters(
     mut commands: Commands,
-    query: Query<(Entity, &MonsterState), With<Monster>>,
+    time: Res<Time>,
+    query: Query<
+        (Entity, &MonsterKind, &Transform, &MonsterState, &SpiritReward, Option<&GoalSides>, Option<&GoalBonuses>),
+        With<Monster>
+    >,
     mut despawn_events: MessageWriter<MonsterDespawnEvent>,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
 ) {
-    for (entity, state) in &query {
+    for (entity, kind, transform, state, spirit_reward, goal_sides, goal_bonuses) in &query {
         if *state == MonsterState::Reached {
-            info!("Monster reached goal, despawning entity {:?}", entity);
+            let side = exit_side(transform.translation.xy());
+            let (cause, event) = match goal_sides {
+                Some(goal_sides) if !goal_sides.accepts(side) => {
+                    info!("Monster left through wrong side {:?}, despawning entity {:?}", side, entity);
+                    (DespawnCause::WrongExit, LifecycleEvent::WrongExit { side })
+                }
+                _ => {
+                    info!("Monster reached goal, despawning entity {:?}", entity);
+                    (DespawnCause::ReachedGoal, LifecycleEvent::ReachedGoal)
+                }
+            };
 
-            // ゴール到達イベントを発行
+            // ゴール到達（または誤った出口）イベントを発行（ボーナスはゴールに到達した場合だけ）
+            let bonuses = match (cause, goal_bonuses) {
+                (DespawnCause::ReachedGoal, Some(goal_bonuses)) => goal_bonuses.0.clone(),
+                _ => Vec::new(),
+            };
             despawn_events.write(MonsterDespawnEvent {
                 entity,
-                cause: DespawnCause::ReachedGoal,
+                cause,
+                spirit_reward: spirit_reward.0,
+                bonuses,
             });
+            lifecycle_events.write(MonsterLifecycleEvent::new(
+                entity,
+                *kind,
+                transform.translation.xy(),
+                time.elapsed_secs(),
+                event,
+            ));
 
             commands.entity(entity).despawn();
         }
@@ -1,12 +1,45 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, Direction, GridPosition, world_to_grid};
+use crate::feature::obstacle::ObstacleKind;
+use super::components::StagingLane;
+use super::definitions::MonsterKind;
 
 /// モンスター消滅イベント
-#[derive(Message, Debug, Clone, Copy)]
+#[derive(Message, Debug, Clone)]
 pub struct MonsterDespawnEvent {
     /// 消滅したモンスターのエンティティ
     pub entity: Entity,
     /// 消滅の原因
     pub cause: DespawnCause,
+    /// ゴール到達時に得られる魂の量
+    pub spirit_reward: f32,
+    /// ゴール到達時に追加で得られるボーナス（ゴール以外で消滅した場合は空）
+    pub bonuses: Vec<GoalBonus>,
+}
+
+/// ゴール到達時に追加で得られるボーナス
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalBonus {
+    /// ボーナスを与えたもの
+    pub source: BonusSource,
+    pub effect: BonusEffect,
+}
+
+/// ボーナスを与えたもの
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BonusSource {
+    /// 祠のそばを通った
+    Shrine { obstacle: Entity, grid_pos: GridPosition },
+}
+
+/// ボーナスの内容
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BonusEffect {
+    /// 魂を増やす
+    Spirit(f32),
+    /// 虚を減らす
+    ReduceVoid(f32),
 }
 
 /// モンスター消滅の原因
@@ -16,4 +49,123 @@ pub enum DespawnCause {
     ReachedGoal,
     /// 待機時間が閾値を超えた
     WaitExpired,
+    /// ゴールではない辺からフィールド外に出た
+    WrongExit,
+}
+
+/// 待機列あふれイベント（レーンの待機列が上限を超えた）
+#[derive(Message, Debug, Clone, Copy)]
+pub struct LaneOverflowEvent {
+    /// あふれたレーン
+    pub lane: StagingLane,
+    /// あふれた時点での待機列の長さ
+    pub queue_length: usize,
+}
+
+/// Wave早期呼び出しイベント（プレイヤーが次のWaveを早めに開始した）
+#[derive(Message, Debug, Clone, Copy)]
+pub struct WaveCalledEarlyEvent {
+    /// 開始したWaveのインデックス
+    pub wave_index: usize,
+    /// 早めに呼んだことによる魂のボーナス
+    pub spirit_bonus: f32,
+}
+
+/// グリッドロック（自然には解消しない渋滞）検出イベント
+#[derive(Message, Debug, Clone)]
+pub struct GridlockDetected {
+    /// グリッドロックの種類
+    pub kind: GridlockKind,
+    /// グリッドロックに関与しているモンスターのエンティティ
+    pub entities: Vec<Entity>,
+    /// 関与しているモンスターがいるマス
+    pub cells: Vec<GridPosition>,
+}
+
+/// グリッドロックの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridlockKind {
+    /// 正面衝突（逆方向に進む2体が互いを塞いでいる）
+    HeadOn,
+    /// 3体以上（または直交する2体）が循環的に互いを塞いでいる
+    Cycle,
+}
+
+/// モンスターのライフサイクルイベント
+///
+/// 音・エフェクト・統計・実績・チュートリアルなどがコンポーネントの状態を
+/// ポーリングせずにモンスターの出来事へ反応できるようにするためのイベント
+#[derive(Message, Debug, Clone, Copy)]
+pub struct MonsterLifecycleEvent {
+    /// 対象のモンスターのエンティティ
+    pub entity: Entity,
+    /// モンスターの種類
+    pub kind: MonsterKind,
+    /// 発生時にモンスターがいたマス
+    pub cell: GridPosition,
+    /// 発生時刻（ゲーム開始からの経過時間・秒）
+    pub time: f32,
+    /// 発生した出来事
+    pub event: LifecycleEvent,
+}
+
+impl MonsterLifecycleEvent {
+    /// ワールド座標からマスを計算してイベントを作成
+    pub fn new(entity: Entity, kind: MonsterKind, position: Vec2, time: f32, event: LifecycleEvent) -> Self {
+        Self {
+            entity,
+            kind,
+            cell: world_to_grid(position, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT),
+            time,
+            event,
+        }
+    }
+}
+
+/// モンスターのライフサイクル上の出来事
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    /// スポーンした（画面端で待機開始）
+    Spawned,
+    /// 待機が終わり移動を開始した
+    StagingFinished,
+    /// 他のモンスターとの衝突が始まった
+    CollisionStarted {
+        other: Option<Entity>,
+    },
+    /// 衝突が解消した
+    CollisionEnded,
+    /// 障害物のマスに入った
+    EnteredObstacle {
+        obstacle: Entity,
+        obstacle_kind: ObstacleKind,
+    },
+    /// 障害物のマスから出た
+    LeftObstacle {
+        obstacle: Entity,
+        obstacle_kind: ObstacleKind,
+    },
+    /// アイテムによって進行方向が変わった
+    DirectionChanged {
+        from: Direction,
+        to: Direction,
+    },
+    /// 風に押し出された
+    WindPushed {
+        from: GridPosition,
+        to: GridPosition,
+    },
+    /// ワープゲートでワープした
+    Teleported {
+        from: GridPosition,
+        to: GridPosition,
+    },
+    /// ゴールに到達した
+    ReachedGoal,
+    /// ゴールではない辺からフィールド外に出た
+    WrongExit {
+        side: Direction,
+    },
+    /// 待機時間が閾値を超えて消滅した
+    WaitExpired,
 }
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, Direction, GridPosition, world_to_grid};
+use super::components::*;
+use super::events::{GridlockDetected, GridlockKind};
+
+/// 検出済みのグリッドロック
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gridlock {
+    pub kind: GridlockKind,
+    /// 関与しているモンスター（最小のエンティティが先頭になるよう正規化済み）
+    pub entities: Vec<Entity>,
+    /// 関与しているモンスターがいるマス
+    pub cells: Vec<GridPosition>,
+}
+
+/// 現在継続中のグリッドロック一覧（HUDでの強調表示などに使用）
+#[derive(Resource, Debug, Default)]
+pub struct ActiveGridlocks {
+    pub gridlocks: Vec<Gridlock>,
+}
+
+impl ActiveGridlocks {
+    /// 指定したモンスターがグリッドロックに巻き込まれているか
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.gridlocks.iter().any(|g| g.entities.contains(&entity))
+    }
+}
+
+/// グリッドロック検出システム
+///
+/// 衝突判定の結果から「誰が誰に塞がれているか」のグラフを作り、
+/// 循環（互いに塞ぎ合っていて自然には解消しない状態）を検出する。
+/// 新たに発生したグリッドロックについてGridlockDetectedイベントを発行する
+pub fn detect_gridlock_system(
+    query: Query<(Entity, &Transform, &Movement, &CollisionState), With<Monster>>,
+    mut active: ResMut<ActiveGridlocks>,
+    mut gridlock_events: MessageWriter<GridlockDetected>,
+) {
+    // 「塞がれている → 塞いでいる相手」の辺を収集（各モンスターの出次数は最大1）
+    let mut blocked_by: HashMap<Entity, Entity> = HashMap::new();
+    let mut info: HashMap<Entity, (Vec2, Direction)> = HashMap::new();
+
+    for (entity, transform, movement, collision_state) in &query {
+        info.insert(entity, (transform.translation.xy(), movement.direction));
+        if let (true, Some(blocker)) = (collision_state.is_colliding, collision_state.blocked_by) {
+            blocked_by.insert(entity, blocker);
+        }
+    }
+
+    let mut gridlocks = Vec::new();
+    for cycle in find_cycles(&blocked_by) {
+        // 相手がすでに存在しない辺を含む循環は無視
+        if cycle.iter().any(|e| !info.contains_key(e)) {
+            continue;
+        }
+
+        let kind = if cycle.len() == 2 && info[&cycle[0]].1 == info[&cycle[1]].1.opposite() {
+            GridlockKind::HeadOn
+        } else {
+            GridlockKind::Cycle
+        };
+
+        let cells = cycle
+            .iter()
+            .map(|e| world_to_grid(info[e].0, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT))
+            .collect();
+
+        gridlocks.push(Gridlock { kind, entities: cycle, cells });
+    }
+
+    // 前フレームにはなかったグリッドロックのみイベントを発行
+    for gridlock in &gridlocks {
+        let is_new = !active
+            .gridlocks
+            .iter()
+            .any(|g| g.entities == gridlock.entities);
+        if is_new {
+            info!(
+                "Gridlock detected ({:?}): {:?} at {:?}",
+                gridlock.kind, gridlock.entities, gridlock.cells
+            );
+            gridlock_events.write(GridlockDetected {
+                kind: gridlock.kind,
+                entities: gridlock.entities.clone(),
+                cells: gridlock.cells.clone(),
+            });
+        }
+    }
+
+    active.gridlocks = gridlocks;
+}
+
+/// 出次数が最大1のグラフから循環をすべて抽出する
+///
+/// 各循環は最小のエンティティが先頭になるよう回転して返す
+fn find_cycles(edges: &HashMap<Entity, Entity>) -> Vec<Vec<Entity>> {
+    let mut visited: HashSet<Entity> = HashSet::new();
+    let mut cycles = Vec::new();
+
+    for &start in edges.keys() {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        // startから辺をたどり、今回の経路上に戻ってきたら循環
+        let mut path: Vec<Entity> = Vec::new();
+        let mut index_on_path: HashMap<Entity, usize> = HashMap::new();
+        let mut current = start;
+
+        loop {
+            if visited.contains(&current) {
+                break;
+            }
+            if let Some(&index) = index_on_path.get(&current) {
+                let mut cycle = path[index..].to_vec();
+                let min_pos = cycle
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, e)| **e)
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                cycle.rotate_left(min_pos);
+                cycles.push(cycle);
+                break;
+            }
+            index_on_path.insert(current, path.len());
+            path.push(current);
+
+            match edges.get(&current) {
+                Some(&next) => current = next,
+                None => break,
+            }
+        }
+
+        visited.extend(path);
+    }
+
+    cycles
+}
@@ -5,14 +5,19 @@ mod spawn;
 mod staging;
 mod movement;
 pub mod collision;
+mod gridlock;
 mod despawn;
 mod wait;
 mod events;
 mod plugin;
 
 pub use components::*;
-pub use definitions::{MonsterDefinition, MonsterDefinitions, MonsterKind, MonsterDefinitionsLoader};
+pub use definitions::{
+    MonsterDefinition, MonsterDefinitionEntry, MonsterDefinitions, MonsterKind, MonsterDefinitionsLoader,
+    resolve_monster_definitions,
+};
 pub use special_behavior::SpecialBehavior;
-pub use spawn::{StageLevel, WaveDefinition, StageLevelLoader};
+pub use spawn::{StageLevel, WaveDefinition, WaveTrigger, SpawnDefinition, SpawnPattern, RandomSpawnTable, StageLevelLoader, StageSelection, MovementMode, LaneError, lane_entry_cell, MonsterSpawnQueue, UpcomingSpawn, get_lane_edge_position, get_staging_slot_position};
 pub use events::*;
+pub use gridlock::{ActiveGridlocks, Gridlock};
 pub use plugin::MonsterPlugin;
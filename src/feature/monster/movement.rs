@@ -1,17 +1,20 @@
 use bevy::prelude::*;
-use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT};
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, Direction};
 use super::components::*;
 
 /// モンスターを移動させるシステム
+///
+/// コンベアなどによる外部速度がある場合は、自分の移動に加算するか置き換える
+#[allow(clippy::type_complexity)]
 pub fn monster_movement_system(
     time: Res<Time>,
-    mut query: Query<(&Movement, &mut Transform, &mut MonsterState, &CollisionState), With<Monster>>,
+    mut query: Query<(&Movement, &mut Transform, &mut MonsterState, &CollisionState, Option<&ExternalVelocity>), With<Monster>>,
 ) {
-    for (movement, mut transform, mut state, collision) in &mut query {
+    for (movement, mut transform, mut state, collision, external) in &mut query {
         // Moving状態で、かつ衝突していない場合、かつ移動が有効な場合のみ移動
         if *state == MonsterState::Moving && !collision.is_colliding && movement.enabled {
             // 進行方向に移動
-            let velocity = movement.direction.to_vector() * movement.speed;
+            let velocity = movement.velocity(external);
             transform.translation += velocity.extend(0.0) * time.delta_secs();
 
             // フィールド外に出たかチェック
@@ -34,3 +37,49 @@ fn is_out_of_bounds(position: Vec2) -> bool {
         || position.y < -field_height / 2.0 - margin
         || position.y > field_height / 2.0 + margin
 }
+
+/// フィールド外に出た位置から、どの辺から出たかを判定
+///
+/// 左右と上下の両方の辺を越えている場合は角から出たとして斜め方向を返す
+pub fn exit_side(position: Vec2) -> Direction {
+    let excess_x = position.x.abs() - FIELD_WIDTH as f32 * GRID_SIZE / 2.0;
+    let excess_y = position.y.abs() - FIELD_HEIGHT as f32 * GRID_SIZE / 2.0;
+
+    if excess_x > 0.0 && excess_y > 0.0 {
+        match (position.x >= 0.0, position.y >= 0.0) {
+            (true, true) => Direction::UpRight,
+            (false, true) => Direction::UpLeft,
+            (true, false) => Direction::DownRight,
+            (false, false) => Direction::DownLeft,
+        }
+    } else if excess_x >= excess_y {
+        if position.x >= 0.0 { Direction::Right } else { Direction::Left }
+    } else if position.y >= 0.0 {
+        Direction::Up
+    } else {
+        Direction::Down
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corner_exits_resolve_to_diagonal_sides() {
+        let half_width = FIELD_WIDTH as f32 * GRID_SIZE / 2.0;
+        let half_height = FIELD_HEIGHT as f32 * GRID_SIZE / 2.0;
+        let outside = GRID_SIZE * 3.0;
+
+        assert_eq!(exit_side(Vec2::new(half_width + outside, 0.0)), Direction::Right);
+        assert_eq!(exit_side(Vec2::new(0.0, -half_height - outside)), Direction::Down);
+        assert_eq!(exit_side(Vec2::new(half_width + outside, half_height + 1.0)), Direction::UpRight);
+        assert_eq!(exit_side(Vec2::new(-half_width - 1.0, -half_height - outside)), Direction::DownLeft);
+
+        // 角から出たモンスターは、斜めの辺か、それを構成する上下左右の辺のどれかが指定されていればゴール
+        assert!(GoalSides(vec![Direction::UpRight]).accepts(Direction::UpRight));
+        assert!(GoalSides(vec![Direction::Right]).accepts(Direction::UpRight));
+        assert!(!GoalSides(vec![Direction::Left]).accepts(Direction::UpRight));
+        assert!(!GoalSides(vec![Direction::UpRight]).accepts(Direction::Right));
+    }
+}
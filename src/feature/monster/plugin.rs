@@ -1,15 +1,17 @@
 use bevy::prelude::*;
 use crate::GameState;
-use crate::core::MonsterDefinitionsAsset;
+use crate::core::{MonsterDefinitionsAsset, GameRng};
 use super::definitions::*;
 use super::special_behavior::*;
 use super::spawn::*;
 use super::staging::*;
 use super::movement::*;
 use super::collision::*;
+use super::gridlock::*;
 use super::despawn::*;
 use super::wait::*;
 use super::events::*;
+use crate::feature::ui::{draw_gridlock_highlight_system, update_spawn_telegraph_system, despawn_spawn_telegraph_system};
 
 /// モンスター機能を提供するプラグイン
 pub struct MonsterPlugin;
@@ -18,7 +20,15 @@ impl Plugin for MonsterPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<MonsterDefinitions>()
+            .init_resource::<ActiveGridlocks>()
+            .init_resource::<GameRng>()
+            .init_resource::<StageSelection>()
+            .init_resource::<MovementMode>()
             .add_message::<MonsterDespawnEvent>()
+            .add_message::<GridlockDetected>()
+            .add_message::<LaneOverflowEvent>()
+            .add_message::<WaveCalledEarlyEvent>()
+            .add_message::<MonsterLifecycleEvent>()
             .add_systems(Startup, (load_monster_definitions_system, load_stage_level_system))
             .add_systems(
                 Update,
@@ -27,8 +37,10 @@ impl Plugin for MonsterPlugin {
                     initialize_spawn_queue_system,
                     spawn_monsters_system,
                     staging_timer_system,
+                    advance_staging_queue_system,
                     my_pace_system,  // 特殊挙動システムを追加
                     collision_detection_system,
+                    detect_gridlock_system,
                     monster_movement_system,
                     update_wait_meter_system,
                     update_monster_color_system,
@@ -37,7 +49,13 @@ impl Plugin for MonsterPlugin {
                 )
                     .chain()
                     .run_if(in_state(GameState::InGame))
-            );
+            )
+            .add_systems(
+                Update,
+                (draw_gridlock_highlight_system, update_spawn_telegraph_system)
+                    .run_if(in_state(GameState::InGame))
+            )
+            .add_systems(OnExit(GameState::InGame), despawn_spawn_telegraph_system);
     }
 }
 
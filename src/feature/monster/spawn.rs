@@ -1,9 +1,16 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use crate::core::{Direction, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, grid_to_world, GridPosition, StageLevelAsset};
+use rand::prelude::*;
+use crate::core::{
+    Direction, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, grid_to_world, is_valid_grid_position, GridPosition, StageLevelAsset, StageMode,
+    GameRng,
+};
 use crate::core::level;
 use super::components::*;
-use super::definitions::{MonsterDefinitions, MonsterKind};
+use super::definitions::{MonsterDefinition, MonsterDefinitions, MonsterKind};
+use super::events::{MonsterLifecycleEvent, LifecycleEvent, LaneOverflowEvent, WaveCalledEarlyEvent};
+use std::collections::HashMap;
+use crate::feature::obstacle::WaveObstacleEvent;
 use super::special_behavior::{SpecialBehavior, MyPaceTimer};
 
 /// ステージレベルのロード状態を管理するリソース
@@ -13,6 +20,60 @@ pub struct StageLevelLoader {
     pub loaded: bool,
 }
 
+/// ステージの移動モード（リソース）
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct MovementMode {
+    /// 斜め移動モード（斜め方向のスポーンを許可し、ぐるぐる床が45度ずつ曲げる）
+    pub diagonal: bool,
+}
+
+impl MovementMode {
+    /// ぐるぐる床で曲がった後の方向
+    pub fn turn(&self, direction: Direction) -> Direction {
+        if self.diagonal {
+            direction.rotate_clockwise_45()
+        } else {
+            direction.rotate_clockwise()
+        }
+    }
+}
+
+/// スポーンできないレーンの理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaneError {
+    /// 斜め移動モードではないステージで斜め方向が指定された
+    DiagonalDisabled,
+    /// 進入するマスがフィールドの外
+    OutOfField,
+}
+
+/// プレイするステージファイルの選択
+#[derive(Resource, Debug, Clone)]
+pub struct StageSelection {
+    /// assetsフォルダからのステージファイルのパス
+    pub path: String,
+}
+
+impl Default for StageSelection {
+    fn default() -> Self {
+        Self {
+            path: "stages/stage1_level1.ron".to_string(),
+        }
+    }
+}
+
+impl StageSelection {
+    /// コマンドライン引数（--stage <path>）からステージを選択する
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        args.iter()
+            .position(|arg| arg == "--stage")
+            .and_then(|index| args.get(index + 1))
+            .map(|path| Self { path: path.clone() })
+            .unwrap_or_default()
+    }
+}
+
 /// モンスターのスポーン定義
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpawnDefinition {
@@ -20,17 +81,279 @@ pub struct SpawnDefinition {
     pub direction: Direction,
     /// スポーン位置（進行方向に垂直な軸の座標）
     /// Right/Leftの場合はy座標、Up/Downの場合はx座標を指定
+    /// 斜め方向の場合は進入する角からのずらし量（0で角から対角線上を進む、
+    /// 正なら上下の辺に沿って、負なら左右の辺に沿ってずらす）
     pub grid_pos: i32,
     pub delay: f32,
+    /// ゴールとなる出口の辺（指定するとモンスター定義の設定を上書きする）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goal_sides: Option<Vec<Direction>>,
+
+    // 以下はこのスポーンだけに適用するパラメータの上書き（省略時はモンスター定義の値）
+    /// 移動速度（px/秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+    /// サイズ（グリッドサイズに対する倍率）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<f32>,
+    /// 待機時間の閾値（秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_threshold: Option<f32>,
+    /// ゴール到達時の魂の増加量
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spirit_reward: Option<f32>,
+    /// 特殊挙動（`special_behavior: None`と書くとモンスター定義の特殊挙動を外す）
+    #[serde(default, with = "super::special_behavior::behavior_override", skip_serializing_if = "Option::is_none")]
+    pub special_behavior: Option<SpecialBehavior>,
+
+    /// 所属するWaveのインデックス（スポーンキューに入るときに設定される）
+    #[serde(skip)]
+    pub wave: Option<usize>,
 }
 
-/// Wave定義（特定の時刻に出現するモンスターのグループ）
+impl SpawnDefinition {
+    /// パラメータの上書きなしでスポーン定義を作成
+    pub fn new(kind: MonsterKind, direction: Direction, grid_pos: i32, delay: f32) -> Self {
+        Self {
+            kind,
+            direction,
+            grid_pos,
+            delay,
+            goal_sides: None,
+            speed: None,
+            size: None,
+            wait_threshold: None,
+            spirit_reward: None,
+            special_behavior: None,
+            wave: None,
+        }
+    }
+
+    /// このスポーンのレーン（方向とgrid_pos）にモンスターが進入できるかチェック
+    pub fn validate_lane(&self, mode: MovementMode) -> Result<(), LaneError> {
+        if self.direction.is_diagonal() && !mode.diagonal {
+            return Err(LaneError::DiagonalDisabled);
+        }
+        lane_entry_cell(self.direction, self.grid_pos)
+            .map(|_| ())
+            .ok_or(LaneError::OutOfField)
+    }
+
+    /// モンスター定義にこのスポーンの上書き設定をマージした定義を返す
+    pub fn resolve_definition(&self, base: &MonsterDefinition) -> MonsterDefinition {
+        MonsterDefinition {
+            speed: self.speed.unwrap_or(base.speed),
+            size: self.size.unwrap_or(base.size),
+            wait_threshold: self.wait_threshold.unwrap_or(base.wait_threshold),
+            spirit_reward: self.spirit_reward.or(base.spirit_reward),
+            special_behavior: self
+                .special_behavior
+                .clone()
+                .unwrap_or_else(|| base.special_behavior.clone()),
+            goal_sides: self.goal_sides.clone().or_else(|| base.goal_sides.clone()),
+            ..base.clone()
+        }
+    }
+}
+
+/// Wave定義（開始条件を満たしたときに出現するモンスターのグループ）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaveDefinition {
     /// Wave開始時間（ゲーム開始からの経過時間・秒）
+    /// triggerがAtTimeの場合のみ使用する
+    #[serde(default)]
     pub start_time: f32,
+    /// Waveの開始条件（省略時はstart_timeに開始）
+    #[serde(default)]
+    pub trigger: WaveTrigger,
     /// このWaveでスポーンするモンスターのリスト
     pub monsters: Vec<SpawnDefinition>,
+    /// 重み付きテーブルからランダムに抽選するスポーン
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub random_spawns: Vec<RandomSpawnTable>,
+    /// 連続出現などのスポーンパターン（ロード時にmonstersに展開される）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<SpawnPattern>,
+    /// Wave中に障害物を置いたり取り除いたりするイベント
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub obstacle_events: Vec<WaveObstacleEvent>,
+}
+
+impl WaveDefinition {
+    /// スポーンパターンをmonstersに展開する
+    pub fn expand_patterns(&mut self) {
+        for pattern in std::mem::take(&mut self.patterns) {
+            self.monsters.extend(pattern.expand());
+        }
+    }
+
+    /// ランダムスポーンを抽選してmonstersに展開する
+    pub fn draw_random_spawns(&mut self, rng: &mut impl Rng) {
+        for table in std::mem::take(&mut self.random_spawns) {
+            self.monsters.extend(table.draw(rng));
+        }
+    }
+}
+
+/// Waveの開始条件
+///
+/// どの条件でも、プレイヤーが「次のWaveを早めに呼ぶ」操作をすればその時点で開始する
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum WaveTrigger {
+    /// start_timeに開始
+    #[default]
+    AtTime,
+    /// 前のWaveのモンスターがすべていなくなったら開始
+    AfterPreviousCleared,
+    /// 前のWaveのモンスターがすべていなくなってから指定秒数後に開始
+    DelayAfterPreviousCleared(f32),
+    /// 前のWaveの開始後、フィールド上のモンスター数が指定数を下回ったら開始
+    PopulationBelow(usize),
+}
+
+/// スポーンパターン（同じようなスポーン定義を並べる代わりに使う短縮記法）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SpawnPattern {
+    /// 同じレーンにinterval秒間隔でcount体
+    Stream {
+        kind: MonsterKind,
+        direction: Direction,
+        grid_pos: i32,
+        count: u32,
+        interval: f32,
+        #[serde(default)]
+        delay: f32,
+    },
+    /// lanesのレーンを順番に切り替えながらinterval秒間隔でcount体
+    Alternating {
+        kind: MonsterKind,
+        direction: Direction,
+        lanes: Vec<i32>,
+        count: u32,
+        interval: f32,
+        #[serde(default)]
+        delay: f32,
+    },
+    /// 同じレーンの反対側の辺からも同時に出現するペアをinterval秒間隔でcount組
+    Mirrored {
+        kind: MonsterKind,
+        direction: Direction,
+        grid_pos: i32,
+        count: u32,
+        interval: f32,
+        #[serde(default)]
+        delay: f32,
+    },
+    /// 出現頻度を正弦波で変化させながら同じレーンにcount体
+    /// 基本間隔intervalに対し、頻度が(1 ± amplitude)倍の範囲でperiod秒周期で揺れる
+    Sine {
+        kind: MonsterKind,
+        direction: Direction,
+        grid_pos: i32,
+        count: u32,
+        interval: f32,
+        amplitude: f32,
+        period: f32,
+        #[serde(default)]
+        delay: f32,
+    },
+}
+
+impl SpawnPattern {
+    /// パターンをスポーン定義のリストに展開する（delayはWave開始からの相対時間）
+    pub fn expand(&self) -> Vec<SpawnDefinition> {
+        match self {
+            SpawnPattern::Stream { kind, direction, grid_pos, count, interval, delay } => (0..*count)
+                .map(|i| SpawnDefinition::new(*kind, *direction, *grid_pos, delay + interval * i as f32))
+                .collect(),
+            SpawnPattern::Alternating { kind, direction, lanes, count, interval, delay } => {
+                if lanes.is_empty() {
+                    warn!("Alternating spawn pattern has no lanes, skipping");
+                    return Vec::new();
+                }
+                (0..*count)
+                    .map(|i| {
+                        let grid_pos = lanes[i as usize % lanes.len()];
+                        SpawnDefinition::new(*kind, *direction, grid_pos, delay + interval * i as f32)
+                    })
+                    .collect()
+            }
+            SpawnPattern::Mirrored { kind, direction, grid_pos, count, interval, delay } => (0..*count)
+                .flat_map(|i| {
+                    let time = delay + interval * i as f32;
+                    [
+                        SpawnDefinition::new(*kind, *direction, *grid_pos, time),
+                        SpawnDefinition::new(*kind, direction.opposite(), *grid_pos, time),
+                    ]
+                })
+                .collect(),
+            SpawnPattern::Sine { kind, direction, grid_pos, count, interval, amplitude, period, delay } => {
+                // 頻度が0以下にならないように振幅を制限
+                let amplitude = amplitude.clamp(0.0, 0.9);
+                let mut time = 0.0;
+                let mut spawns = Vec::new();
+                for _ in 0..*count {
+                    spawns.push(SpawnDefinition::new(*kind, *direction, *grid_pos, delay + time));
+                    let phase = std::f32::consts::TAU * time / period.max(f32::EPSILON);
+                    let rate = (1.0 + amplitude * phase.sin()) / interval;
+                    time += 1.0 / rate;
+                }
+                spawns
+            }
+        }
+    }
+}
+
+/// 重み付きランダムスポーンテーブル
+///
+/// 種類・方向・レーンをそれぞれ重み付きで抽選し、count体のスポーンを生成する
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomSpawnTable {
+    /// 抽選するスポーン数
+    pub count: u32,
+    /// モンスターの種類と重み
+    pub kinds: Vec<(MonsterKind, f32)>,
+    /// 進行方向と重み
+    pub directions: Vec<(Direction, f32)>,
+    /// レーン（grid_pos）と重み
+    pub lanes: Vec<(i32, f32)>,
+    /// 最初のスポーンのWave開始からの遅延（秒）
+    #[serde(default)]
+    pub delay: f32,
+    /// スポーン間隔（秒）
+    pub interval: f32,
+    /// スポーン時刻のゆらぎ幅（±秒）
+    #[serde(default)]
+    pub jitter: f32,
+}
+
+impl RandomSpawnTable {
+    /// テーブルからスポーンを抽選する（delayはWave開始からの相対時間）
+    pub fn draw(&self, rng: &mut impl Rng) -> Vec<SpawnDefinition> {
+        let mut spawns = Vec::new();
+
+        for i in 0..self.count {
+            let (Ok((kind, _)), Ok((direction, _)), Ok((grid_pos, _))) = (
+                self.kinds.choose_weighted(rng, |(_, w)| *w),
+                self.directions.choose_weighted(rng, |(_, w)| *w),
+                self.lanes.choose_weighted(rng, |(_, w)| *w),
+            ) else {
+                warn!("Random spawn table has no valid weights, skipping");
+                return spawns;
+            };
+
+            let jitter = if self.jitter > 0.0 {
+                rng.gen_range(-self.jitter..=self.jitter)
+            } else {
+                0.0
+            };
+            let delay = (self.delay + self.interval * i as f32 + jitter).max(0.0);
+
+            spawns.push(SpawnDefinition::new(*kind, *direction, *grid_pos, delay));
+        }
+
+        spawns
+    }
 }
 
 /// ステージレベル定義
@@ -48,6 +371,12 @@ pub struct MonsterSpawnQueue {
     pub waves: Vec<WaveDefinition>,
     pub timer: f32,
     pub processed_wave_indices: Vec<usize>,  // 処理済みWaveのインデックス
+    /// Waveが実際に開始した時刻
+    pub wave_start_times: HashMap<usize, f32>,
+    /// Waveのモンスターがすべていなくなった時刻
+    pub wave_cleared_times: HashMap<usize, f32>,
+    /// スポーン予告の先読み時間（秒）
+    pub telegraph_look_ahead: f32,
 }
 
 impl MonsterSpawnQueue {
@@ -57,14 +386,175 @@ impl MonsterSpawnQueue {
             waves,
             timer: 0.0,
             processed_wave_indices: Vec::new(),
+            wave_start_times: HashMap::new(),
+            wave_cleared_times: HashMap::new(),
+            telegraph_look_ahead: level::SPAWN_TELEGRAPH_LOOK_AHEAD,
+        }
+    }
+
+    /// 前のWaveがいなくなった時刻（最初のWaveはゲーム開始時に片付いているとみなす）
+    fn previous_wave_cleared_time(&self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(0.0),
+            _ => self.wave_cleared_times.get(&(index - 1)).copied(),
+        }
+    }
+
+    /// 前のWaveが開始済みか（最初のWaveは常に開始済みとみなす）
+    fn previous_wave_started(&self, index: usize) -> bool {
+        index == 0 || self.wave_start_times.contains_key(&(index - 1))
+    }
+
+    /// 指定したWaveより前のWaveで、まだスポーンしていないモンスターの数
+    fn pending_spawns_before(&self, index: usize) -> usize {
+        self.spawns
+            .iter()
+            .filter(|spawn_def| spawn_def.wave.is_some_and(|wave| wave < index))
+            .count()
+    }
+
+    /// Waveを早めに呼べるか（前のWaveのモンスターがフィールドにいる間だけ呼べる）
+    ///
+    /// 呼んだWaveのモンスターがフィールドに出るまでは次のWaveを呼べないので、
+    /// 連打で残りのWaveをまとめて開始してボーナスを集めることはできない
+    fn can_call_early(&self, index: usize, wave_populations: &HashMap<usize, usize>) -> bool {
+        index > 0
+            && self.previous_wave_started(index)
+            && wave_populations.get(&(index - 1)).is_some_and(|&population| population > 0)
+    }
+
+    /// 開始条件を満たしたWaveのインデックスを返す
+    ///
+    /// populationはフィールド上のモンスター数。PopulationBelowの判定では、
+    /// それより前のWaveのまだスポーンしていないモンスターも数に入れる
+    fn due_waves(&self, population: usize) -> Vec<usize> {
+        self.waves
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.processed_wave_indices.contains(index))
+            .filter(|(index, wave)| match wave.trigger {
+                WaveTrigger::AtTime => self.timer >= wave.start_time,
+                WaveTrigger::AfterPreviousCleared => self.previous_wave_cleared_time(*index).is_some(),
+                WaveTrigger::DelayAfterPreviousCleared(delay) => self
+                    .previous_wave_cleared_time(*index)
+                    .is_some_and(|cleared| self.timer >= cleared + delay),
+                // 前のWaveより先に開始しないように、前のWaveが開始してから判定する
+                WaveTrigger::PopulationBelow(threshold) => {
+                    self.previous_wave_started(*index) && population + self.pending_spawns_before(*index) < threshold
+                }
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Waveを追加する（エンドレスモードで生成したWaveなど）
+    pub fn push_wave(&mut self, wave: WaveDefinition) {
+        self.waves.push(wave);
+    }
+
+    /// まだ開始していない最初のWave
+    pub fn next_pending_wave(&self) -> Option<usize> {
+        (0..self.waves.len()).find(|index| !self.processed_wave_indices.contains(index))
+    }
+
+    /// Waveを開始し、モンスターをスポーンキューに追加する
+    fn start_wave(&mut self, index: usize) {
+        let start_time = self.timer;
+        let new_spawns: Vec<_> = self.waves[index]
+            .monsters
+            .iter()
+            .map(|monster_spawn| {
+                let mut spawn_def = monster_spawn.clone();
+                // delayはWave開始時間からの相対時間なので、絶対時間に変換
+                spawn_def.delay = start_time + monster_spawn.delay;
+                spawn_def.wave = Some(index);
+                spawn_def
+            })
+            .collect();
+
+        self.spawns.extend(new_spawns);
+        self.processed_wave_indices.push(index);
+        self.wave_start_times.insert(index, start_time);
+        info!("Wave {} started at {:.2}s", index, start_time);
+    }
+
+    /// 開始済みのWaveのうち、スポーン待ちもフィールド上のモンスターもいなくなったWaveを記録する
+    fn update_cleared_waves(&mut self, wave_populations: &HashMap<usize, usize>) {
+        for index in self.processed_wave_indices.clone() {
+            if self.wave_cleared_times.contains_key(&index) {
+                continue;
+            }
+            let pending = self.spawns.iter().any(|spawn_def| spawn_def.wave == Some(index));
+            let alive = wave_populations.get(&index).copied().unwrap_or(0) > 0;
+            if !pending && !alive {
+                self.wave_cleared_times.insert(index, self.timer);
+                info!("Wave {} cleared at {:.2}s", index, self.timer);
+            }
+        }
+    }
+
+    /// 先読み時間内にスポーン予定のモンスターを返す
+    ///
+    /// キュー内のスポーンに加え、まだ開始していないWaveのモンスターも含む
+    pub fn upcoming_spawns(&self, look_ahead: f32) -> Vec<UpcomingSpawn> {
+        let mut upcoming = Vec::new();
+
+        // キュー内のスポーン（delayは絶対時間）
+        for spawn_def in &self.spawns {
+            let remaining = spawn_def.delay - self.timer;
+            if remaining <= look_ahead {
+                upcoming.push(UpcomingSpawn::new(spawn_def, remaining.max(0.0)));
+            }
+        }
+
+        // 未開始のWave（delayはWave開始時間からの相対時間）
+        // 開始時刻が決まっているAtTimeのWaveのみ予告できる
+        for (index, wave) in self.waves.iter().enumerate() {
+            if self.processed_wave_indices.contains(&index) || wave.trigger != WaveTrigger::AtTime {
+                continue;
+            }
+            for spawn_def in &wave.monsters {
+                let remaining = wave.start_time + spawn_def.delay - self.timer;
+                if remaining <= look_ahead {
+                    upcoming.push(UpcomingSpawn::new(spawn_def, remaining.max(0.0)));
+                }
+            }
+        }
+
+        upcoming.sort_by(|a, b| a.remaining.total_cmp(&b.remaining));
+        upcoming
+    }
+}
+
+/// スポーン予告情報
+#[derive(Debug, Clone, Copy)]
+pub struct UpcomingSpawn {
+    pub kind: MonsterKind,
+    pub direction: Direction,
+    pub grid_pos: i32,
+    /// スポーンまでの残り時間（秒）
+    pub remaining: f32,
+}
+
+impl UpcomingSpawn {
+    fn new(spawn_def: &SpawnDefinition, remaining: f32) -> Self {
+        Self {
+            kind: spawn_def.kind,
+            direction: spawn_def.direction,
+            grid_pos: spawn_def.grid_pos,
+            remaining,
         }
     }
 }
 
 
 /// ステージレベルアセットをロードするシステム（起動時に一度だけ実行）
-pub fn load_stage_level_system(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let handle: Handle<StageLevelAsset> = asset_server.load("stages/stage1_level1.ron");
+pub fn load_stage_level_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    selection: Res<StageSelection>,
+) {
+    let handle: Handle<StageLevelAsset> = asset_server.load(selection.path.clone());
     commands.insert_resource(StageLevelLoader {
         handle,
         loaded: false,
@@ -76,6 +566,8 @@ pub fn initialize_spawn_queue_system(
     mut commands: Commands,
     mut loader: ResMut<StageLevelLoader>,
     stage_assets: Res<Assets<StageLevelAsset>>,
+    mut rng: ResMut<GameRng>,
+    mut movement_mode: ResMut<MovementMode>,
 ) {
     // すでにロード済みならスキップ
     if loader.loaded {
@@ -84,8 +576,41 @@ pub fn initialize_spawn_queue_system(
 
     // アセットがロードされたか確認
     if let Some(stage_asset) = stage_assets.get(&loader.handle) {
-        let stage_level = stage_asset.to_stage_level();
-        commands.insert_resource(MonsterSpawnQueue::new(stage_level.waves));
+        let mut stage_level = stage_asset.to_stage_level();
+
+        // ステージのシードで乱数を初期化し、ランダムスポーンを抽選
+        rng.reseed(stage_asset.seed.unwrap_or(level::DEFAULT_RNG_SEED));
+        for wave in &mut stage_level.waves {
+            wave.draw_random_spawns(&mut *rng);
+        }
+
+        // 斜め移動モードでない場合の斜めのレーンや、フィールドの外になるレーンのスポーンは取り除く
+        *movement_mode = MovementMode { diagonal: stage_asset.diagonal_movement };
+        for wave in &mut stage_level.waves {
+            wave.monsters.retain(|spawn_def| match spawn_def.validate_lane(*movement_mode) {
+                Ok(()) => true,
+                Err(error) => {
+                    warn!(
+                        "Spawn {:?} {:?} at lane {} skipped: {:?}",
+                        spawn_def.kind, spawn_def.direction, spawn_def.grid_pos, error
+                    );
+                    false
+                }
+            });
+        }
+
+        // エンドレスモード（デイリーチャレンジを含む）ではステージのWaveを使わず、
+        // endless機能がWaveを追加していく
+        let waves = match stage_asset.mode {
+            StageMode::Fixed => stage_level.waves,
+            StageMode::Endless | StageMode::Daily => Vec::new(),
+        };
+
+        let mut spawn_queue = MonsterSpawnQueue::new(waves);
+        if let Some(look_ahead) = stage_asset.spawn_telegraph_look_ahead {
+            spawn_queue.telegraph_look_ahead = look_ahead;
+        }
+        commands.insert_resource(spawn_queue);
         loader.loaded = true;
         info!("Stage level loaded: Stage {}, Level {}", stage_level.stage, stage_level.level);
     }
@@ -93,9 +618,22 @@ pub fn initialize_spawn_queue_system(
 
 /// 画面端の待機位置を取得
 fn get_staging_position(direction: Direction, grid_pos: i32) -> Vec3 {
+    get_lane_edge_position(direction, grid_pos, GRID_SIZE * 1.5)
+}
+
+/// 待機列の位置を取得（index 0が進入地点、それ以降は1マスずつ外側に並ぶ）
+pub fn get_staging_slot_position(lane: StagingLane, index: usize) -> Vec3 {
+    let entry = get_staging_position(lane.direction, lane.grid_pos);
+    let direction_vector = lane.direction.to_vector();
+    // 斜め方向でも各軸で1マス分ずつ離れるように間隔を調整
+    let step = GRID_SIZE / direction_vector.x.abs().max(direction_vector.y.abs());
+    entry - direction_vector.extend(0.0) * step * index as f32
+}
+
+/// レーンの進入側のフィールド端から外側にmarginだけ離れた位置を取得
+pub fn get_lane_edge_position(direction: Direction, grid_pos: i32, margin: f32) -> Vec3 {
     let field_width = FIELD_WIDTH as f32 * GRID_SIZE;
     let field_height = FIELD_HEIGHT as f32 * GRID_SIZE;
-    let margin = GRID_SIZE * 1.5;
 
     match direction {
         Direction::Right => {
@@ -122,16 +660,64 @@ fn get_staging_position(direction: Direction, grid_pos: i32) -> Vec3 {
             let world_pos = grid_to_world(grid_position, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
             Vec3::new(world_pos.x, field_height / 2.0 + margin, 0.0)
         }
+        Direction::UpRight | Direction::UpLeft | Direction::DownRight | Direction::DownLeft => {
+            // 角のマスから進入、grid_posは角からのずらし量
+            let entry_cell = diagonal_entry_cell(direction, grid_pos);
+            let world_pos = grid_to_world(entry_cell, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+
+            // 進入マスの中心から進行方向の逆向きに下がり、辺からmarginだけ外側に置く
+            let back = (GRID_SIZE / 2.0 + margin) * std::f32::consts::SQRT_2;
+            (world_pos - direction.to_vector() * back).extend(0.0)
+        }
+    }
+}
+
+/// 斜め方向のレーンで最初に進入するマス
+///
+/// grid_posが0以上なら角から上下の辺に沿って、負なら左右の辺に沿ってずらす
+fn diagonal_entry_cell(direction: Direction, grid_pos: i32) -> GridPosition {
+    let direction_vector = direction.to_vector();
+    let step_x = direction_vector.x.signum() as i32;
+    let step_y = direction_vector.y.signum() as i32;
+    let corner_x = if step_x > 0 { 0 } else { FIELD_WIDTH - 1 };
+    let corner_y = if step_y > 0 { 0 } else { FIELD_HEIGHT - 1 };
+
+    if grid_pos >= 0 {
+        GridPosition::new(corner_x + step_x * grid_pos, corner_y)
+    } else {
+        GridPosition::new(corner_x, corner_y - step_y * grid_pos)
     }
 }
 
+/// レーンでモンスターが最初に進入するマス（フィールドの外になるレーンはNone）
+pub fn lane_entry_cell(direction: Direction, grid_pos: i32) -> Option<GridPosition> {
+    let cell = match direction {
+        Direction::Right => GridPosition::new(0, grid_pos),
+        Direction::Left => GridPosition::new(FIELD_WIDTH - 1, grid_pos),
+        Direction::Up => GridPosition::new(grid_pos, 0),
+        Direction::Down => GridPosition::new(grid_pos, FIELD_HEIGHT - 1),
+        _ => diagonal_entry_cell(direction, grid_pos),
+    };
+    is_valid_grid_position(cell, FIELD_WIDTH, FIELD_HEIGHT).then_some(cell)
+}
+
 /// モンスターをスポーンするシステム
+///
+/// Waveの開始条件の評価と、「次のWaveを早めに呼ぶ」操作（Nキー）の処理も行う
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_monsters_system(
     mut commands: Commands,
     time: Res<Time>,
     spawn_queue: Option<ResMut<MonsterSpawnQueue>>,
     monster_defs: Res<MonsterDefinitions>,
     asset_server: Res<AssetServer>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    slot_query: Query<&StagingSlot>,
+    monster_query: Query<(), With<Monster>>,
+    wave_member_query: Query<&WaveMember>,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
+    mut overflow_events: MessageWriter<LaneOverflowEvent>,
+    mut early_call_events: MessageWriter<WaveCalledEarlyEvent>,
 ) {
     // MonsterSpawnQueueが初期化されるまで待機
     let Some(mut spawn_queue) = spawn_queue else {
@@ -139,41 +725,68 @@ pub fn spawn_monsters_system(
     };
     spawn_queue.timer += time.delta_secs();
 
-    // Wave開始時間を確認して、新しいWaveのモンスターをスポーンキューに追加
-    let mut newly_processed_waves = Vec::new();
-    let mut new_spawns = Vec::new();
+    // 開始済みWaveの片付き状況を更新
+    let mut wave_populations: HashMap<usize, usize> = HashMap::new();
+    for wave_member in &wave_member_query {
+        *wave_populations.entry(wave_member.0).or_insert(0) += 1;
+    }
+    spawn_queue.update_cleared_waves(&wave_populations);
 
-    for (index, wave) in spawn_queue.waves.iter().enumerate() {
-        // すでに処理済みのWaveはスキップ
-        if spawn_queue.processed_wave_indices.contains(&index) {
-            continue;
-        }
+    // 開始条件を満たしたWaveのモンスターをスポーンキューに追加
+    for index in spawn_queue.due_waves(monster_query.iter().count()) {
+        spawn_queue.start_wave(index);
+    }
 
-        // Wave開始時間に達したか確認
-        if spawn_queue.timer >= wave.start_time {
-            // このWaveのモンスターをスポーンキューに追加
-            for monster_spawn in &wave.monsters {
-                let mut spawn_def = monster_spawn.clone();
-                // delayはWave開始時間からの相対時間なので、絶対時間に変換
-                spawn_def.delay = wave.start_time + monster_spawn.delay;
-                new_spawns.push(spawn_def);
-            }
-            newly_processed_waves.push(index);
-            info!("Wave {} started at {:.2}s", index, spawn_queue.timer);
+    // プレイヤーが次のWaveを早めに呼んだ場合は、条件を待たずに開始してボーナスを与える
+    // （Waveは一度しか開始しないので、ボーナスは1つのWaveにつき1回まで）
+    if keyboard.just_pressed(KeyCode::KeyN)
+        && let Some(index) = spawn_queue.next_pending_wave()
+    {
+        if spawn_queue.can_call_early(index, &wave_populations) {
+            spawn_queue.start_wave(index);
+            info!("Wave {} called early", index);
+            early_call_events.write(WaveCalledEarlyEvent {
+                wave_index: index,
+                spirit_bonus: level::EARLY_CALL_SPIRIT_BONUS,
+            });
+        } else {
+            info!("Wave {} cannot be called early until the previous wave is on the field", index);
         }
     }
 
-    // 新しいスポーンをキューに追加
-    spawn_queue.spawns.extend(new_spawns);
-
-    // 処理済みWaveをマーク
-    spawn_queue.processed_wave_indices.extend(newly_processed_waves);
+    // レーンごとの待機列の長さを集計
+    let mut lane_lengths: HashMap<StagingLane, usize> = HashMap::new();
+    for slot in &slot_query {
+        *lane_lengths.entry(slot.lane).or_insert(0) += 1;
+    }
 
     // スポーン予定のモンスターをチェック
     let mut spawned_indices = Vec::new();
     for (index, spawn_def) in spawn_queue.spawns.iter().enumerate() {
         if spawn_queue.timer >= spawn_def.delay {
-            spawn_monster(&mut commands, spawn_def, &monster_defs, &asset_server);
+            // 同じレーンの待機列の最後尾に並ぶ
+            let lane = StagingLane::new(spawn_def.direction, spawn_def.grid_pos);
+            let queue_length = lane_lengths.entry(lane).or_insert(0);
+            let slot = StagingSlot { lane, index: *queue_length };
+            *queue_length += 1;
+
+            // 待機列が上限を超えたら虚を増加させる
+            if *queue_length > level::STAGING_QUEUE_LIMIT {
+                warn!("Staging queue overflow on {:?}: {} monsters", lane, queue_length);
+                overflow_events.write(LaneOverflowEvent {
+                    lane,
+                    queue_length: *queue_length,
+                });
+            }
+
+            let entity = spawn_monster(&mut commands, spawn_def, slot, &monster_defs, &asset_server);
+            lifecycle_events.write(MonsterLifecycleEvent::new(
+                entity,
+                spawn_def.kind,
+                get_staging_slot_position(lane, slot.index).xy(),
+                time.elapsed_secs(),
+                LifecycleEvent::Spawned,
+            ));
             spawned_indices.push(index);
         }
     }
@@ -184,15 +797,16 @@ pub fn spawn_monsters_system(
     }
 }
 
-/// モンスターをスポーン
+/// モンスターをスポーンし、生成したエンティティを返す
 fn spawn_monster(
     commands: &mut Commands,
     spawn_def: &SpawnDefinition,
+    slot: StagingSlot,
     monster_defs: &MonsterDefinitions,
     asset_server: &AssetServer,
-) {
-    let def = monster_defs.get(spawn_def.kind);
-    let position = get_staging_position(spawn_def.direction, spawn_def.grid_pos);
+) -> Entity {
+    let def = spawn_def.resolve_definition(monster_defs.get(spawn_def.kind));
+    let position = get_staging_slot_position(slot.lane, slot.index);
     let monster_size = GRID_SIZE * def.size;
 
     // テクスチャを読み込む
@@ -211,9 +825,11 @@ fn spawn_monster(
         ),
         Movement::new(spawn_def.direction, def.speed),
         StagingTimer::new(level::STAGING_DURATION),
+        slot,
         CollisionBox::new(Vec2::splat(monster_size)),
         CollisionState::new(),
         WaitMeter::new(def.wait_threshold),
+        SpiritReward(def.spirit_reward.unwrap_or(level::SPIRIT_GAIN_PER_GOAL)),
         def.special_behavior.clone(),  // SpecialBehaviorコンポーネントを追加
         Sprite {
             image: texture_handle,
@@ -224,6 +840,16 @@ fn spawn_monster(
         Transform::from_translation(position),
     ));
 
+    // Waveに所属している場合は、WaveMemberコンポーネントを追加
+    if let Some(wave) = spawn_def.wave {
+        entity_commands.insert(WaveMember(wave));
+    }
+
+    // 出口の指定がある場合は、GoalSidesコンポーネントを追加
+    if let Some(sides) = &def.goal_sides {
+        entity_commands.insert(GoalSides(sides.clone()));
+    }
+
     // MyPace挙動の場合は、MyPaceTimerコンポーネントを追加
     if let SpecialBehavior::MyPace { stop_interval, stop_duration } = def.special_behavior {
         entity_commands.insert(MyPaceTimer::new(stop_interval, stop_duration));
@@ -233,4 +859,204 @@ fn spawn_monster(
         "Spawned {:?} at {:?} (grid: {}) facing {:?}",
         spawn_def.kind, position, spawn_def.grid_pos, spawn_def.direction
     );
+
+    entity_commands.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wave(trigger: WaveTrigger, monster_count: usize) -> WaveDefinition {
+        WaveDefinition {
+            start_time: 0.0,
+            trigger,
+            monsters: (0..monster_count)
+                .map(|index| SpawnDefinition::new(MonsterKind::Kappa, Direction::Right, 0, index as f32))
+                .collect(),
+            random_spawns: Vec::new(),
+            patterns: Vec::new(),
+            obstacle_events: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn population_below_counts_pending_spawns_of_previous_waves() {
+        let mut queue = MonsterSpawnQueue::new(vec![
+            wave(WaveTrigger::AtTime, 3),
+            wave(WaveTrigger::PopulationBelow(2), 3),
+            wave(WaveTrigger::PopulationBelow(2), 3),
+        ]);
+
+        // フィールドが空でも、前のWaveが開始するまでは開始しない
+        assert_eq!(queue.due_waves(0), vec![0]);
+        queue.start_wave(0);
+
+        // Wave 0のモンスターがスポーン待ちの間は開始しない
+        assert!(queue.due_waves(0).is_empty());
+
+        // Wave 0のモンスターがスポーンしてフィールドに出た後、数が減ったら開始する
+        queue.spawns.clear();
+        assert!(queue.due_waves(3).is_empty());
+        assert_eq!(queue.due_waves(1), vec![1]);
+        queue.start_wave(1);
+
+        // Wave 1のスポーン待ちがいるので、Wave 2は続けて開始しない
+        assert!(queue.due_waves(1).is_empty());
+    }
+
+    #[test]
+    fn early_call_requires_previous_wave_on_field() {
+        let mut queue = MonsterSpawnQueue::new(vec![
+            wave(WaveTrigger::AtTime, 1),
+            wave(WaveTrigger::AfterPreviousCleared, 1),
+            wave(WaveTrigger::AfterPreviousCleared, 1),
+        ]);
+        queue.start_wave(0);
+        let mut populations = HashMap::new();
+
+        // Wave 0のモンスターがまだフィールドに出ていない
+        assert!(!queue.can_call_early(1, &populations));
+
+        populations.insert(0, 1);
+        assert!(queue.can_call_early(1, &populations));
+        queue.start_wave(1);
+
+        // 呼んだばかりのWave 1はまだフィールドにいないので、続けては呼べない
+        assert!(!queue.can_call_early(2, &populations));
+    }
+
+    #[test]
+    fn diagonal_lanes_require_diagonal_mode_and_stay_inside_the_field() {
+        let normal = MovementMode { diagonal: false };
+        let diagonal = MovementMode { diagonal: true };
+        let spawn = |direction, grid_pos| SpawnDefinition::new(MonsterKind::Kappa, direction, grid_pos, 0.0);
+
+        assert_eq!(spawn(Direction::Right, 0).validate_lane(normal), Ok(()));
+        assert_eq!(spawn(Direction::Right, FIELD_HEIGHT).validate_lane(normal), Err(LaneError::OutOfField));
+        assert_eq!(spawn(Direction::UpRight, 0).validate_lane(normal), Err(LaneError::DiagonalDisabled));
+        assert_eq!(spawn(Direction::UpRight, 0).validate_lane(diagonal), Ok(()));
+        assert_eq!(spawn(Direction::DownLeft, -2).validate_lane(diagonal), Ok(()));
+        assert_eq!(spawn(Direction::UpRight, FIELD_WIDTH).validate_lane(diagonal), Err(LaneError::OutOfField));
+        assert_eq!(spawn(Direction::UpRight, -FIELD_HEIGHT).validate_lane(diagonal), Err(LaneError::OutOfField));
+    }
+
+    #[test]
+    fn diagonal_mode_turns_by_45_degrees() {
+        assert_eq!(MovementMode { diagonal: false }.turn(Direction::Up), Direction::Up.rotate_clockwise());
+        assert_eq!(MovementMode { diagonal: true }.turn(Direction::Up), Direction::Up.rotate_clockwise_45());
+    }
+
+    fn weighted_table(count: u32) -> RandomSpawnTable {
+        RandomSpawnTable {
+            count,
+            kinds: vec![(MonsterKind::Kappa, 3.0), (MonsterKind::Ghost, 1.0), (MonsterKind::Bakeneko, 0.0)],
+            directions: vec![(Direction::Right, 1.0), (Direction::Left, 0.0)],
+            lanes: vec![(0, 1.0), (1, 1.0), (2, 0.0)],
+            delay: 1.0,
+            interval: 0.5,
+            jitter: 0.2,
+        }
+    }
+
+    #[test]
+    fn random_spawns_are_reproducible_per_seed() {
+        let draw = |seed| format!("{:?}", weighted_table(10).draw(&mut GameRng::new(seed)));
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+
+    #[test]
+    fn random_spawns_follow_weights() {
+        let spawns = weighted_table(4000).draw(&mut GameRng::new(1));
+        assert_eq!(spawns.len(), 4000);
+
+        // 重み0の候補は選ばれない
+        assert!(spawns.iter().all(|spawn_def| spawn_def.kind != MonsterKind::Bakeneko));
+        assert!(spawns.iter().all(|spawn_def| spawn_def.direction == Direction::Right));
+        assert!(spawns.iter().all(|spawn_def| spawn_def.grid_pos != 2));
+
+        // 重み3:1で選ばれる
+        let kappa = spawns.iter().filter(|spawn_def| spawn_def.kind == MonsterKind::Kappa).count();
+        let ratio = kappa as f32 / spawns.len() as f32;
+        assert!((ratio - 0.75).abs() < 0.03, "Kappa ratio {}", ratio);
+    }
+
+    /// 展開したスポーンの(方向, レーン, 遅延)
+    fn lanes_and_delays(pattern: SpawnPattern) -> Vec<(Direction, i32, f32)> {
+        pattern
+            .expand()
+            .iter()
+            .map(|spawn_def| (spawn_def.direction, spawn_def.grid_pos, spawn_def.delay))
+            .collect()
+    }
+
+    #[test]
+    fn stream_pattern_repeats_one_lane() {
+        let spawns = lanes_and_delays(SpawnPattern::Stream {
+            kind: MonsterKind::Kappa,
+            direction: Direction::Up,
+            grid_pos: 2,
+            count: 3,
+            interval: 1.5,
+            delay: 1.0,
+        });
+        assert_eq!(spawns, vec![(Direction::Up, 2, 1.0), (Direction::Up, 2, 2.5), (Direction::Up, 2, 4.0)]);
+    }
+
+    #[test]
+    fn alternating_pattern_cycles_lanes() {
+        let spawns = lanes_and_delays(SpawnPattern::Alternating {
+            kind: MonsterKind::Kappa,
+            direction: Direction::Right,
+            lanes: vec![1, 4],
+            count: 3,
+            interval: 2.0,
+            delay: 0.0,
+        });
+        assert_eq!(spawns, vec![(Direction::Right, 1, 0.0), (Direction::Right, 4, 2.0), (Direction::Right, 1, 4.0)]);
+    }
+
+    #[test]
+    fn mirrored_pattern_spawns_pairs_from_opposite_sides() {
+        let spawns = lanes_and_delays(SpawnPattern::Mirrored {
+            kind: MonsterKind::Kappa,
+            direction: Direction::Left,
+            grid_pos: 3,
+            count: 2,
+            interval: 1.0,
+            delay: 0.5,
+        });
+        assert_eq!(
+            spawns,
+            vec![
+                (Direction::Left, 3, 0.5),
+                (Direction::Right, 3, 0.5),
+                (Direction::Left, 3, 1.5),
+                (Direction::Right, 3, 1.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn sine_pattern_varies_interval_with_phase() {
+        let spawns = lanes_and_delays(SpawnPattern::Sine {
+            kind: MonsterKind::Kappa,
+            direction: Direction::Down,
+            grid_pos: 0,
+            count: 3,
+            interval: 1.0,
+            amplitude: 0.5,
+            period: 4.0,
+            delay: 2.0,
+        });
+
+        // 位相0では基本間隔、1/4周期では頻度が1.5倍になり間隔が短くなる
+        let expected = [2.0, 3.0, 3.0 + 1.0 / 1.5];
+        assert_eq!(spawns.len(), expected.len());
+        for ((direction, grid_pos, delay), expected) in spawns.into_iter().zip(expected) {
+            assert_eq!((direction, grid_pos), (Direction::Down, 0));
+            assert!((delay - expected).abs() < 1e-4, "delay {} expected {}", delay, expected);
+        }
+    }
 }
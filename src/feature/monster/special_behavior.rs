@@ -18,6 +18,26 @@ pub enum SpecialBehavior {
     },
 }
 
+/// 特殊挙動の上書き設定（Option<SpecialBehavior>）の読み書き
+///
+/// フィールドを省略すると継承し、`special_behavior: None`と書くと特殊挙動を外す。
+/// IMPLICIT_SOMEではNoneがOption::Noneとして読まれて省略と区別できないので、値をSomeで包まずに直接読み書きする
+pub(crate) mod behavior_override {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::SpecialBehavior;
+
+    pub fn serialize<S: Serializer>(value: &Option<SpecialBehavior>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(behavior) => behavior.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<SpecialBehavior>, D::Error> {
+        SpecialBehavior::deserialize(deserializer).map(Some)
+    }
+}
+
 /// マイペース挙動用のタイマーコンポーネント
 #[derive(Component)]
 pub struct MyPaceTimer {
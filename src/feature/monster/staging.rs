@@ -1,17 +1,86 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 use super::components::*;
+use super::definitions::MonsterKind;
+use super::events::{MonsterLifecycleEvent, LifecycleEvent};
+use super::spawn::get_staging_slot_position;
 
 /// 待機タイマーを更新し、時間が来たらMoving状態に遷移
+///
+/// 待機列の先頭（進入地点）にいるモンスターのみタイマーが進む
+#[allow(clippy::type_complexity)]
 pub fn staging_timer_system(
+    mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(&mut StagingTimer, &mut MonsterState), With<Monster>>,
+    mut query: Query<(Entity, &MonsterKind, &Transform, &mut StagingTimer, &mut MonsterState, Option<&StagingSlot>), With<Monster>>,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
 ) {
-    for (mut timer, mut state) in &mut query {
+    for (entity, kind, transform, mut timer, mut state, slot) in &mut query {
         if *state == MonsterState::Staging {
+            // 待機列の後ろに並んでいる間は待つ
+            if slot.is_some_and(|slot| slot.index > 0) {
+                continue;
+            }
+
             timer.remaining -= time.delta_secs();
             if timer.remaining <= 0.0 {
                 *state = MonsterState::Moving;
+                commands.entity(entity).remove::<StagingSlot>();
                 info!("Monster started moving");
+
+                lifecycle_events.write(MonsterLifecycleEvent::new(
+                    entity,
+                    *kind,
+                    transform.translation.xy(),
+                    time.elapsed_secs(),
+                    LifecycleEvent::StagingFinished,
+                ));
+            }
+        }
+    }
+}
+
+/// 進入地点が空いたレーンの待機列を1つ前に詰めるシステム
+///
+/// 先頭のモンスターが出発し、進入地点から十分に離れたら後ろのモンスターが前に進む
+#[allow(clippy::type_complexity)]
+pub fn advance_staging_queue_system(
+    mut slot_query: Query<(&mut StagingSlot, &mut Transform, &CollisionBox), With<Monster>>,
+    moving_query: Query<(&Transform, &CollisionBox, &MonsterState), (With<Monster>, Without<StagingSlot>)>,
+) {
+    // レーンごとに先頭が空いているかを集計
+    let mut front_occupied: HashMap<StagingLane, bool> = HashMap::new();
+    for (slot, _, _) in &slot_query {
+        let occupied = front_occupied.entry(slot.lane).or_insert(false);
+        *occupied |= slot.index == 0;
+    }
+
+    for (lane, occupied) in front_occupied {
+        if occupied {
+            continue;
+        }
+
+        // 進入地点に出発したばかりのモンスターが残っていないか確認
+        let entry = get_staging_slot_position(lane, 0).xy();
+        let entry_blocked = moving_query.iter().any(|(transform, collision_box, state)| {
+            if *state != MonsterState::Moving {
+                return false;
+            }
+            let distance = (transform.translation.xy() - entry).abs();
+            let reach = collision_box.size / 2.0 + Vec2::splat(crate::core::GRID_SIZE / 2.0);
+            distance.x < reach.x && distance.y < reach.y
+        });
+        if entry_blocked {
+            continue;
+        }
+
+        // 待機列を1つ前に詰める
+        for (mut slot, mut transform, _) in &mut slot_query {
+            if slot.lane == lane && slot.index > 0 {
+                slot.index -= 1;
+                let position = get_staging_slot_position(lane, slot.index);
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
             }
         }
     }
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
-use super::components::{Monster, WaitMeter, CollisionState, MonsterState};
-use super::events::{MonsterDespawnEvent, DespawnCause};
+use super::components::{Monster, WaitMeter, CollisionState, MonsterState, SpiritReward};
+use super::definitions::MonsterKind;
+use super::events::{MonsterDespawnEvent, DespawnCause, MonsterLifecycleEvent, LifecycleEvent};
 
 /// モンスターの待機時間を更新
 ///
@@ -58,13 +59,15 @@ pub fn update_monster_color_system(
 
 /// wait値が閾値を超えたモンスターを消滅させる
 ///
-/// 消滅時にMonsterDespawnEventを発行する
+/// 消滅時にMonsterDespawnEventとMonsterLifecycleEventを発行する
 pub fn despawn_expired_monsters_system(
     mut commands: Commands,
-    query: Query<(Entity, &WaitMeter), With<Monster>>,
+    time: Res<Time>,
+    query: Query<(Entity, &MonsterKind, &Transform, &WaitMeter, &SpiritReward), With<Monster>>,
     mut despawn_events: MessageWriter<MonsterDespawnEvent>,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
 ) {
-    for (entity, wait_meter) in query.iter() {
+    for (entity, kind, transform, wait_meter, spirit_reward) in query.iter() {
         if wait_meter.is_expired() {
             info!(
                 "Monster despawned due to wait timeout ({:.1}s >= {:.1}s)",
@@ -75,7 +78,16 @@ pub fn despawn_expired_monsters_system(
             despawn_events.write(MonsterDespawnEvent {
                 entity,
                 cause: DespawnCause::WaitExpired,
+                spirit_reward: spirit_reward.0,
+                bonuses: Vec::new(),
             });
+            lifecycle_events.write(MonsterLifecycleEvent::new(
+                entity,
+                *kind,
+                transform.translation.xy(),
+                time.elapsed_secs(),
+                LifecycleEvent::WaitExpired,
+            ));
 
             // エンティティを削除
             commands.entity(entity).despawn();
@@ -0,0 +1,245 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::core::types::{world_to_grid, is_valid_grid_position};
+use crate::core::config::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, OBSTACLE_CLEAR_ANIMATION_DURATION};
+use crate::feature::item::get_mouse_world_position;
+use crate::feature::monster::{
+    Monster, MonsterKind, Movement, MonsterProperty, ExternalVelocity, StaticCollider, MonsterLifecycleEvent, LifecycleEvent,
+};
+use crate::feature::player::PlayerGauges;
+use super::components::{
+    Obstacle, ObstacleKind, ObstaclePosition, ObstacleCellSprite, ObstacleDirection, Destructible, ObstacleClearing, SwampEffect, WindEffect,
+    WindGust, WallEffect, OneWayEffect, ConveyorEffect, TeleporterEffect, ShrineEffect, OnSwamp, OnConveyor, InObstacle,
+};
+use super::events::ObstacleClearedEvent;
+
+/// 右クリックで障害物を削るシステム
+///
+/// 1回削るごとに魂を消費し、耐久値が0になった障害物は効果を失って消えていく
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn clear_obstacle_on_click_system(
+    mut commands: Commands,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut obstacle_query: Query<
+        (Entity, &ObstacleKind, &ObstaclePosition, Option<&mut Destructible>, &mut Sprite, Option<&Children>),
+        With<Obstacle>
+    >,
+    clearable_query: Query<(Entity, &ObstacleKind, &ObstaclePosition, Option<&TeleporterEffect>), With<Obstacle>>,
+    mut cell_sprite_query: Query<&mut Sprite, (With<ObstacleCellSprite>, Without<Obstacle>)>,
+    mut gauges: ResMut<PlayerGauges>,
+    mut cleared_events: MessageWriter<ObstacleClearedEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Some(world_pos) = get_mouse_world_position(&windows, &camera_query) else {
+        return;
+    };
+    let grid_pos = world_to_grid(world_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+    if !is_valid_grid_position(grid_pos, FIELD_WIDTH, FIELD_HEIGHT) {
+        return;
+    }
+
+    let Some((entity, kind, _, destructible, mut sprite, children)) = obstacle_query
+        .iter_mut()
+        .find(|(_, _, obstacle_pos, _, _, _)| obstacle_pos.covers(grid_pos))
+    else {
+        return;
+    };
+
+    let Some(mut destructible) = destructible else {
+        info!("障害物除去失敗: {:?} は取り除けません", kind);
+        return;
+    };
+
+    // 魂を消費（不足していれば削れない）
+    if !gauges.spirit.consume(destructible.clear_cost) {
+        info!(
+            "障害物除去失敗: 魂が不足しています（必要: {}, 現在: {:.1}）",
+            destructible.clear_cost,
+            gauges.spirit.current
+        );
+        return;
+    }
+
+    destructible.remaining = destructible.remaining.saturating_sub(1);
+    info!(
+        "障害物を削った: {:?} 残り{}/{} 魂 -{} ({:.1}/{:.1})",
+        kind,
+        destructible.remaining,
+        destructible.durability,
+        destructible.clear_cost,
+        gauges.spirit.current,
+        gauges.spirit.max
+    );
+
+    // 削るほど薄くなる
+    let ratio = destructible.remaining as f32 / destructible.durability as f32;
+    let alpha = 0.4 + 0.6 * ratio;
+    sprite.color.set_alpha(alpha);
+    for child in children.into_iter().flatten() {
+        if let Ok(mut cell_sprite) = cell_sprite_query.get_mut(*child) {
+            cell_sprite.color.set_alpha(alpha);
+        }
+    }
+
+    if destructible.remaining > 0 {
+        return;
+    }
+
+    clear_obstacle(&mut commands, &mut cleared_events, &clearable_query, entity);
+}
+
+/// 障害物を取り除いて、ObstacleClearedEventを送る
+///
+/// ワープゲートの場合は、つながっているもう一方の出入口も一緒に取り除く（取り除いたエンティティを返す）
+pub(super) fn clear_obstacle(
+    commands: &mut Commands,
+    cleared_events: &mut MessageWriter<ObstacleClearedEvent>,
+    obstacle_query: &Query<(Entity, &ObstacleKind, &ObstaclePosition, Option<&TeleporterEffect>), With<Obstacle>>,
+    entity: Entity,
+) -> Vec<Entity> {
+    let Ok((_, _, _, teleporter)) = obstacle_query.get(entity) else {
+        return Vec::new();
+    };
+
+    let mut targets = vec![entity];
+    if let Some(teleporter) = teleporter
+        && let Some((partner, _, _, _)) = obstacle_query.iter().find(|(other, _, other_pos, other_teleporter)| {
+            *other != entity && other_teleporter.is_some() && other_pos.covers(teleporter.destination)
+        })
+    {
+        targets.push(partner);
+    }
+
+    for &target in &targets {
+        let Ok((_, kind, obstacle_pos, _)) = obstacle_query.get(target) else {
+            continue;
+        };
+        begin_obstacle_clearing(commands, target);
+        cleared_events.write(ObstacleClearedEvent {
+            entity: target,
+            kind: *kind,
+            grid_pos: obstacle_pos.grid_pos,
+            cells: obstacle_pos.cells.clone(),
+        });
+    }
+
+    targets
+}
+
+/// 障害物の効果をすべて外して、消えるアニメーションを開始する
+///
+/// 呼び出し側でObstacleClearedEventを送り、モンスターに残ったマーカーを外す
+fn begin_obstacle_clearing(commands: &mut Commands, entity: Entity) {
+    commands
+        .entity(entity)
+        .remove::<(
+            Obstacle,
+            Destructible,
+            ObstacleDirection,
+            StaticCollider,
+            SwampEffect,
+            WindEffect,
+            WindGust,
+            WallEffect,
+            OneWayEffect,
+            ConveyorEffect,
+            TeleporterEffect,
+            ShrineEffect,
+        )>()
+        .insert(ObstacleClearing {
+            elapsed: 0.0,
+            duration: OBSTACLE_CLEAR_ANIMATION_DURATION,
+        });
+}
+
+/// 取り除かれた障害物に関係するマーカーをモンスターから外すシステム
+#[allow(clippy::type_complexity)]
+pub fn cleanup_cleared_obstacle_markers_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cleared_events: MessageReader<ObstacleClearedEvent>,
+    mut monster_query: Query<
+        (
+            Entity,
+            &MonsterKind,
+            &Transform,
+            &MonsterProperty,
+            &mut Movement,
+            Option<&InObstacle>,
+            Option<&OnSwamp>,
+            Option<&OnConveyor>,
+        ),
+        With<Monster>
+    >,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
+) {
+    for event in cleared_events.read() {
+        for (entity, kind, transform, property, mut movement, in_obstacle, on_swamp, on_conveyor) in &mut monster_query {
+            let position = transform.translation.xy();
+
+            if let Some(in_obstacle) = in_obstacle
+                && in_obstacle.obstacle == event.entity
+            {
+                commands.entity(entity).remove::<InObstacle>();
+                lifecycle_events.write(MonsterLifecycleEvent::new(
+                    entity,
+                    *kind,
+                    position,
+                    time.elapsed_secs(),
+                    LifecycleEvent::LeftObstacle {
+                        obstacle: event.entity,
+                        obstacle_kind: event.kind,
+                    },
+                ));
+            }
+
+            // 泥沼のマーカーは障害物を参照していないので、マスで判定して速度も元に戻す
+            if on_swamp.is_some()
+                && event.kind == ObstacleKind::Swamp
+                && event.cells.contains(&world_to_grid(position, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT))
+            {
+                commands.entity(entity).remove::<OnSwamp>();
+                movement.speed = property.base_speed;
+            }
+
+            if let Some(on_conveyor) = on_conveyor
+                && on_conveyor.conveyor == event.entity
+            {
+                commands.entity(entity).remove::<(OnConveyor, ExternalVelocity)>();
+            }
+        }
+    }
+}
+
+/// 取り除かれた障害物を縮めながら消して、最後にデスポーンするシステム
+pub fn obstacle_clearing_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut ObstacleClearing, &mut Transform, &mut Sprite, Option<&Children>)>,
+    mut cell_sprite_query: Query<&mut Sprite, (With<ObstacleCellSprite>, Without<ObstacleClearing>)>,
+) {
+    for (entity, mut clearing, mut transform, mut sprite, children) in &mut query {
+        clearing.elapsed += time.delta_secs();
+        let t = (clearing.elapsed / clearing.duration).min(1.0);
+
+        // 子エンティティのマスも一緒に縮む
+        transform.scale = Vec3::splat(1.0 - t);
+        let alpha = 0.4 * (1.0 - t);
+        sprite.color.set_alpha(alpha);
+        for child in children.into_iter().flatten() {
+            if let Ok(mut cell_sprite) = cell_sprite_query.get_mut(*child) {
+                cell_sprite.color.set_alpha(alpha);
+            }
+        }
+
+        if t >= 1.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use crate::core::types::GridPosition;
+use crate::core::types::{Direction, GridPosition};
+use crate::feature::monster::BonusEffect;
 use serde::{Deserialize, Serialize};
 
 /// 障害物の基本コンポーネント
@@ -11,14 +12,45 @@ pub struct Obstacle;
 pub enum ObstacleKind {
     Swamp,  // 泥沼
     Wind,   // 風
+    Wall,   // 壁
+    OneWay,    // 一方通行ゲート
+    Conveyor,  // コンベア
+    Teleporter,  // ワープゲート（2マスで1組）
+    Shrine,  // 祠（そばを通ってゴールしたモンスターがボーナスをもたらす）
+}
+
+impl ObstacleKind {
+    /// 自動生成で置いてよい障害物の種類（レーンをふさいでしまう壁は含めない）
+    pub const PROCEDURAL: [ObstacleKind; 2] = [ObstacleKind::Swamp, ObstacleKind::Wind];
 }
 
 /// 障害物の配置情報
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone)]
 pub struct ObstaclePosition {
+    /// 配置の基準になるマス（ObstacleDefinitionのgrid_pos）
     pub grid_pos: GridPosition,
+    /// 覆っているすべてのマス
+    pub cells: Vec<GridPosition>,
+}
+
+impl ObstaclePosition {
+    /// 指定したマスを覆っているか
+    pub fn covers(&self, grid_pos: GridPosition) -> bool {
+        self.cells.contains(&grid_pos)
+    }
+
+    /// 指定したマスが、覆っているマスのどれかから縦横斜めにradiusマス以内にあるか
+    pub fn is_within(&self, grid_pos: GridPosition, radius: i32) -> bool {
+        self.cells
+            .iter()
+            .any(|cell| (cell.x - grid_pos.x).abs().max((cell.y - grid_pos.y).abs()) <= radius)
+    }
 }
 
+/// 複数のマスを覆う障害物の、基準以外のマスの表示に使う子エンティティのマーカー
+#[derive(Component)]
+pub struct ObstacleCellSprite;
+
 /// 泥沼の効果コンポーネント
 #[derive(Component, Debug, Clone, Copy)]
 pub struct SwampEffect {
@@ -35,11 +67,165 @@ impl Default for SwampEffect {
 }
 
 /// 風の効果コンポーネント
+#[derive(Component, Debug, Clone)]
+pub struct WindEffect {
+    /// 押し出す向きの決め方
+    pub mode: WindMode,
+    /// 押し出すマス数
+    pub push_distance: i32,
+    /// 押し出しアニメーション時間（秒）
+    pub push_duration: f32,
+    /// trueなら押し出した向きにモンスターの進行方向も変える
+    pub turn_heading: bool,
+}
+
+impl Default for WindEffect {
+    fn default() -> Self {
+        Self {
+            mode: WindMode::default(),
+            push_distance: crate::core::level::WIND_PUSH_DISTANCE,
+            push_duration: crate::core::level::WIND_PUSH_DURATION,
+            turn_heading: false,
+        }
+    }
+}
+
+/// 風が押し出す向きの決め方
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WindMode {
+    /// 常に同じ向きに押し出す
+    Fixed(Direction),
+    /// 指定した向きの中からランダムに押し出す（フィールドの外や壁に向かう向きは選ばれない）
+    Random { allowed: Vec<Direction> },
+    /// interval秒ごとに向きが順番に切り替わる突風
+    Rotating { directions: Vec<Direction>, interval: f32 },
+}
+
+impl Default for WindMode {
+    fn default() -> Self {
+        WindMode::Random {
+            allowed: vec![Direction::Up, Direction::Down, Direction::Left, Direction::Right],
+        }
+    }
+}
+
+/// 突風の状態（Rotatingモードの風に付ける）
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WindGust {
+    /// 現在の向きのインデックス
+    pub index: usize,
+    /// 現在の向きになってからの経過時間（秒）
+    pub elapsed: f32,
+}
+
+/// 壁の効果コンポーネント（モンスターは壁を通り抜けられない）
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct WallEffect {
+    pub behavior: WallBehavior,
+}
+
+/// 壁にぶつかったモンスターの挙動
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WallBehavior {
+    /// その場で止まる（他のモンスターとの衝突と同じく待機時間が増える）
+    #[default]
+    Stop,
+    /// 90度右に曲がる
+    TurnRight,
+    /// 引き返す
+    TurnBack,
+}
+
+/// 障害物の向き（一方通行ゲートやコンベアの矢印）
 #[derive(Component, Debug, Clone, Copy)]
-pub struct WindEffect;
+pub struct ObstacleDirection(pub Direction);
+
+/// 一方通行ゲートの効果コンポーネント（矢印と逆向きに進むモンスターは通れない）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OneWayEffect {
+    pub direction: Direction,
+}
+
+/// コンベアの効果コンポーネント（上にいるモンスターを矢印の向きに運ぶ）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ConveyorEffect {
+    pub direction: Direction,
+    /// 運ぶ速さ（px/秒）
+    pub speed: f32,
+    pub mode: ConveyorMode,
+}
+
+/// コンベアの速度の加え方
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConveyorMode {
+    /// モンスター自身の移動に加算する
+    #[default]
+    Add,
+    /// モンスター自身の移動を止めて、コンベアの速度だけで運ぶ
+    Replace,
+}
+
+/// コンベアの上にいることを示すマーカーコンポーネント
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OnConveyor {
+    pub conveyor: Entity,
+}
+
+/// 押し戻しのある潮の帯の中にいることを示すマーカーコンポーネント
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InTide;
+
+/// ワープゲートの効果コンポーネント（入ったモンスターをもう一方の出入口に移動させる）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TeleporterEffect {
+    /// もう一方の出入口のマス
+    pub destination: GridPosition,
+    /// ワープ後に再びワープできるようになるまでの時間（秒）
+    pub cooldown: f32,
+}
+
+/// 潮の帯のコンポーネント（帯の表示に使うスプライトを持つエンティティに付ける）
+#[derive(Component, Debug, Clone)]
+pub struct Tide {
+    pub definition: super::definitions::TideDefinition,
+}
+
+/// 祠の効果コンポーネント（モンスターは祠のマスも通り抜けられる）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShrineEffect {
+    /// ご利益が届く範囲（祠のマスからのマス数）
+    pub radius: i32,
+    /// 範囲を通ったモンスターがゴールしたときのボーナス
+    pub bonus: BonusEffect,
+}
+
+/// 取り除ける障害物のコンポーネント（右クリックで魂を払って削る）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Destructible {
+    /// 取り除くのに必要な回数
+    pub durability: u32,
+    /// 残りの回数
+    pub remaining: u32,
+    /// 1回削るときの魂コスト
+    pub clear_cost: f32,
+}
+
+/// 取り除かれて消えていく途中の障害物のコンポーネント（効果はすでに無くなっている）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ObstacleClearing {
+    pub elapsed: f32,
+    pub duration: f32,
+}
 
 /// 泥沼の上にいることを示すマーカーコンポーネント
 #[derive(Component, Debug, Clone, Copy)]
 pub struct OnSwamp {
     pub speed_multiplier: f32,
 }
+
+/// 障害物のマスにいることを示すマーカーコンポーネント（出入りの検出に使用）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InObstacle {
+    pub obstacle: Entity,
+    pub kind: ObstacleKind,
+}
@@ -1,35 +1,395 @@
+use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use crate::core::types::GridPosition;
-use super::components::ObstacleKind;
+use std::collections::HashMap;
+use crate::core::types::{Direction, GridPosition, is_valid_grid_position};
+use crate::core::config::{FIELD_WIDTH, FIELD_HEIGHT};
+use crate::feature::monster::BonusEffect;
+use super::components::{ConveyorMode, ObstacleKind, WallBehavior, WindMode};
 
 /// 障害物のスポーン定義
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObstacleDefinition {
     pub kind: ObstacleKind,
     pub grid_pos: GridPosition,
+    /// 複数のマスを覆う場合の形（省略時はgrid_posの1マスだけ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footprint: Option<Footprint>,
+    /// 障害物の向き（一方通行ゲートとコンベアで使用、省略時はRight）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+    /// つながっている先のマス（ワープゲートで使用、このマスにもう一方の出入口が置かれる）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<GridPosition>,
+    /// この配置だけに適用する効果パラメータ（省略時はobstacles.ronの値）
+    #[serde(default, skip_serializing_if = "ObstacleParams::is_empty")]
+    pub params: ObstacleParams,
 }
 
-/// 障害物の視覚設定
-#[derive(Debug, Clone)]
-pub struct ObstacleVisualConfig {
+impl ObstacleDefinition {
+    /// パラメータの上書きなしで障害物の配置を作成
+    pub fn new(kind: ObstacleKind, grid_pos: GridPosition) -> Self {
+        Self {
+            kind,
+            grid_pos,
+            footprint: None,
+            direction: None,
+            link: None,
+            params: ObstacleParams::default(),
+        }
+    }
+}
+
+impl ObstacleDefinition {
+    /// この障害物が覆うマス（重複は除く）
+    pub fn cells(&self) -> Vec<GridPosition> {
+        let offsets: Vec<GridPosition> = match &self.footprint {
+            None => vec![GridPosition::new(0, 0)],
+            Some(Footprint::Rect { width, height }) => (0..*height)
+                .flat_map(|dy| (0..*width).map(move |dx| GridPosition::new(dx, dy)))
+                .collect(),
+            Some(Footprint::Cells(offsets)) => offsets.clone(),
+        };
+
+        let mut cells = Vec::new();
+        for offset in offsets {
+            let cell = GridPosition::new(self.grid_pos.x + offset.x, self.grid_pos.y + offset.y);
+            if !cells.contains(&cell) {
+                cells.push(cell);
+            }
+        }
+        cells
+    }
+
+    /// ワープゲートのもう一方の出入口も含めた、この配置が置かれるすべてのマス
+    pub fn occupied_cells(&self) -> Vec<GridPosition> {
+        let mut cells = self.cells();
+        if let (ObstacleKind::Teleporter, Some(link)) = (self.kind, self.link) {
+            let partner = ObstacleDefinition {
+                grid_pos: link,
+                ..self.clone()
+            };
+            cells.extend(partner.cells());
+        }
+        cells
+    }
+
+    /// フィールドの範囲内に収まり、occupiedのマスや自分自身と重ならずに置けるかチェック
+    ///
+    /// ワープゲートはlinkが必須で、もう一方の出入口もフィールド内に収まり重ならないこと
+    pub fn validate_placement(&self, occupied: &[GridPosition]) -> Result<(), PlacementError> {
+        let cells = self.occupied_cells();
+        if self.cells().is_empty() {
+            return Err(PlacementError::EmptyFootprint);
+        }
+        if self.kind == ObstacleKind::Teleporter && self.link.is_none() {
+            return Err(PlacementError::MissingLink);
+        }
+
+        for (index, cell) in cells.iter().enumerate() {
+            if !is_valid_grid_position(*cell, FIELD_WIDTH, FIELD_HEIGHT) {
+                return Err(PlacementError::OutOfBounds(*cell));
+            }
+            // ワープゲートの出入口どうしが重なる場合も不正とする
+            if occupied.contains(cell) || cells[..index].contains(cell) {
+                return Err(PlacementError::Overlaps(*cell));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 障害物が覆うマスの形
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Footprint {
+    /// grid_posを左下の角とする長方形（マス数）
+    Rect { width: i32, height: i32 },
+    /// grid_posからの相対位置で指定したマスの集まり（grid_pos自身を含める場合は(x: 0, y: 0)も指定する）
+    Cells(Vec<GridPosition>),
+}
+
+/// 障害物を置けない理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    /// 覆うマスが1つもない
+    EmptyFootprint,
+    /// フィールドの外にはみ出すマスがある
+    OutOfBounds(GridPosition),
+    /// 他の障害物と重なるマスがある
+    Overlaps(GridPosition),
+    /// ワープゲートにもう一方の出入口（link）の指定がない
+    MissingLink,
+}
+
+/// Wave中の障害物イベント（Wave開始からdelay秒後に実行する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveObstacleEvent {
+    /// Wave開始からの時間（秒）
+    /// 予告時間より短い場合も指定どおりの時刻に実行し、予告はWave開始から実行までの間だけ表示する
+    #[serde(default)]
+    pub delay: f32,
+    pub action: ObstacleAction,
+}
+
+/// 障害物イベントの内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ObstacleAction {
+    /// 障害物を置く（既に障害物があるマスには置かない。壁はモンスターがいるマスが空くまで待ってから置く）
+    Place {
+        obstacle: ObstacleDefinition,
+        /// trueならこのWaveのモンスターがいなくなったときに取り除く
+        #[serde(default)]
+        remove_on_wave_end: bool,
+    },
+    /// マスにある障害物を取り除く（ワープゲートはもう一方の出入口も取り除く）
+    Remove { grid_pos: GridPosition },
+}
+
+/// 潮の満ち引きの定義（行または列をまとめて覆う帯が周期的に前後する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TideDefinition {
+    /// 帯が覆う向き（Rowsなら行全体を覆って上下に、Columnsなら列全体を覆って左右に動く）
+    pub axis: TideAxis,
+    /// 引き潮のときの帯の開始位置（行または列の番号）
+    pub origin: f32,
+    /// 満ち潮のときに帯が進むマス数（負の値なら逆向きに進む）
+    pub amplitude: f32,
+    /// 帯の幅（マス数）
+    pub width: f32,
+    /// 満ち引きの周期（秒、0.0より大きい値）
+    pub period: f32,
+    /// 周期のずれ（0.0～1.0、0.0なら引き潮から始まる）
+    #[serde(default)]
+    pub phase: f32,
+    /// 帯の中にいるモンスターの速度倍率（0.0以上、0.0なら帯の中で止まる）
+    pub speed_multiplier: f32,
+    /// 帯の中にいるモンスターを帯が進む向きと逆向きに押し戻す速さ（px/秒、0.0以上、省略時は押し戻さない）
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub push_back_speed: f32,
+}
+
+fn is_zero(value: &f32) -> bool {
+    *value == 0.0
+}
+
+/// 潮の定義が不正な理由
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TideError {
+    /// 周期が0.0以下
+    NonPositivePeriod(f32),
+    /// 速度倍率が負（後ろ向きに動かす場合はpush_back_speedを使う）
+    NegativeSpeedMultiplier(f32),
+    /// 押し戻す速さが負
+    NegativePushBackSpeed(f32),
+}
+
+/// 潮の帯が覆う向き
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TideAxis {
+    Rows,
+    Columns,
+}
+
+impl TideDefinition {
+    /// 周期と速度倍率が扱える値かチェック
+    pub fn validate(&self) -> Result<(), TideError> {
+        if self.period.is_nan() || self.period <= 0.0 {
+            return Err(TideError::NonPositivePeriod(self.period));
+        }
+        if self.speed_multiplier.is_nan() || self.speed_multiplier < 0.0 {
+            return Err(TideError::NegativeSpeedMultiplier(self.speed_multiplier));
+        }
+        if self.push_back_speed.is_nan() || self.push_back_speed < 0.0 {
+            return Err(TideError::NegativePushBackSpeed(self.push_back_speed));
+        }
+        Ok(())
+    }
+
+    /// 帯の中にいるモンスターに加える外部速度（帯が満ちてくる向きと逆向き）
+    pub fn push_back_velocity(&self) -> Vec2 {
+        let advance = match self.axis {
+            TideAxis::Rows => Vec2::Y,
+            TideAxis::Columns => Vec2::X,
+        } * self.amplitude.signum();
+        -advance * self.push_back_speed
+    }
+
+    /// 指定した時刻の帯の範囲（行または列の番号、開始以上・終了未満）
+    pub fn band(&self, time: f32) -> (f32, f32) {
+        let cycle = time / self.period + self.phase;
+        let progress = (1.0 - (cycle * std::f32::consts::TAU).cos()) / 2.0;
+        let start = self.origin + self.amplitude * progress;
+        (start, start + self.width)
+    }
+}
+
+/// 障害物の効果パラメータ（種類ごとに使うものだけ指定する）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObstacleParams {
+    /// 泥沼: 速度倍率（0.5 = 半分）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed_multiplier: Option<f32>,
+    /// 風: 押し出すマス数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_distance: Option<i32>,
+    /// 風: 押し出しアニメーション時間（秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_duration: Option<f32>,
+    /// 風: 押し出す向きの決め方
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wind_mode: Option<WindMode>,
+    /// 風: 押し出した向きにモンスターの進行方向も変えるか
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turn_heading: Option<bool>,
+    /// 壁: ぶつかったモンスターの挙動
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wall_behavior: Option<WallBehavior>,
+    /// コンベア: 運ぶ速さ（px/秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conveyor_speed: Option<f32>,
+    /// コンベア: 速度の加え方
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conveyor_mode: Option<ConveyorMode>,
+    /// ワープゲート: ワープ後に再びワープできるようになるまでの時間（秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teleport_cooldown: Option<f32>,
+    /// 祠: ご利益が届く範囲（祠のマスからのマス数）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shrine_radius: Option<i32>,
+    /// 祠: 範囲を通ったモンスターがゴールしたときのボーナス
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shrine_bonus: Option<BonusEffect>,
+    /// 共通: 取り除くのに必要な回数（省略時は取り除けない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub durability: Option<u32>,
+    /// 共通: 1回削るときの魂コスト
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_cost: Option<f32>,
+}
+
+impl ObstacleParams {
+    /// 何も指定されていないか
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// overridesで指定されたパラメータを優先してマージする
+    pub fn merged_with(&self, overrides: &ObstacleParams) -> ObstacleParams {
+        ObstacleParams {
+            speed_multiplier: overrides.speed_multiplier.or(self.speed_multiplier),
+            push_distance: overrides.push_distance.or(self.push_distance),
+            push_duration: overrides.push_duration.or(self.push_duration),
+            wind_mode: overrides.wind_mode.clone().or_else(|| self.wind_mode.clone()),
+            turn_heading: overrides.turn_heading.or(self.turn_heading),
+            wall_behavior: overrides.wall_behavior.or(self.wall_behavior),
+            conveyor_speed: overrides.conveyor_speed.or(self.conveyor_speed),
+            conveyor_mode: overrides.conveyor_mode.or(self.conveyor_mode),
+            teleport_cooldown: overrides.teleport_cooldown.or(self.teleport_cooldown),
+            shrine_radius: overrides.shrine_radius.or(self.shrine_radius),
+            shrine_bonus: overrides.shrine_bonus.or(self.shrine_bonus),
+            durability: overrides.durability.or(self.durability),
+            clear_cost: overrides.clear_cost.or(self.clear_cost),
+        }
+    }
+}
+
+/// obstacles.ronのエントリ（障害物の種類ごとの見た目と効果パラメータ）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObstacleCatalogEntry {
     pub kind: ObstacleKind,
-    pub color: (f32, f32, f32),
+    /// テクスチャファイルのパス（省略時はcolorで塗りつぶす）
+    #[serde(default)]
+    pub texture_path: Option<String>,
+    pub color: (f32, f32, f32),  // テクスチャがない場合のフォールバック
     pub size: f32,  // グリッドサイズに対する倍率（0.8 = 80%）
+    #[serde(default)]
+    pub params: ObstacleParams,
 }
 
-impl ObstacleVisualConfig {
-    pub fn get_config(kind: ObstacleKind) -> Self {
-        match kind {
-            ObstacleKind::Swamp => Self {
-                kind,
-                color: (0.4, 0.3, 0.2),  // 茶色
-                size: 0.9,
-            },
-            ObstacleKind::Wind => Self {
-                kind,
-                color: (0.6, 0.9, 1.0),  // 水色
-                size: 0.8,
-            },
+/// 障害物の種類ごとの設定を管理するリソース
+#[derive(Resource, Default)]
+pub struct ObstacleCatalog {
+    pub(crate) entries: HashMap<ObstacleKind, ObstacleCatalogEntry>,
+}
+
+impl ObstacleCatalog {
+    /// エントリのリストから作成
+    pub fn from_entries(entries: Vec<ObstacleCatalogEntry>) -> Self {
+        Self {
+            entries: entries.into_iter().map(|entry| (entry.kind, entry)).collect(),
+        }
+    }
+
+    /// 指定された種類の障害物の設定を取得
+    pub fn get(&self, kind: ObstacleKind) -> &ObstacleCatalogEntry {
+        self.entries
+            .get(&kind)
+            .expect("Obstacle catalog entry not found")
+    }
+}
+
+/// 障害物カタログのロード状態を管理するリソース
+#[derive(Resource)]
+pub struct ObstacleCatalogLoader {
+    pub handle: Handle<crate::core::ObstacleCatalogAsset>,
+    pub loaded: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tide(phase: f32, push_back_speed: f32) -> TideDefinition {
+        TideDefinition {
+            axis: TideAxis::Rows,
+            origin: -2.0,
+            amplitude: 3.0,
+            width: 2.0,
+            period: 20.0,
+            phase,
+            speed_multiplier: 0.5,
+            push_back_speed,
         }
     }
+
+    #[test]
+    fn band_follows_phase() {
+        // 0.0は引き潮、0.5は満ち潮、1.0は1周して引き潮に戻る
+        let (start, end) = tide(0.0, 0.0).band(0.0);
+        assert!((start - -2.0).abs() < 1e-5 && (end - 0.0).abs() < 1e-5);
+        let (start, end) = tide(0.5, 0.0).band(0.0);
+        assert!((start - 1.0).abs() < 1e-5 && (end - 3.0).abs() < 1e-5);
+        let (start, end) = tide(1.0, 0.0).band(0.0);
+        assert!((start - -2.0).abs() < 1e-5 && (end - 0.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn push_back_opposes_advance_direction() {
+        assert_eq!(tide(0.0, 0.0).push_back_velocity(), Vec2::ZERO);
+        assert_eq!(tide(0.0, 40.0).push_back_velocity(), Vec2::new(0.0, -40.0));
+
+        let receding_columns = TideDefinition {
+            axis: TideAxis::Columns,
+            amplitude: -3.0,
+            ..tide(0.0, 40.0)
+        };
+        assert_eq!(receding_columns.push_back_velocity(), Vec2::new(40.0, 0.0));
+        assert_eq!(tide(0.0, -1.0).validate(), Err(TideError::NegativePushBackSpeed(-1.0)));
+    }
+
+    #[test]
+    fn teleporter_link_is_validated() {
+        let teleporter = |link| ObstacleDefinition {
+            link,
+            ..ObstacleDefinition::new(ObstacleKind::Teleporter, GridPosition::new(1, 1))
+        };
+
+        assert_eq!(teleporter(None).validate_placement(&[]), Err(PlacementError::MissingLink));
+        assert_eq!(teleporter(Some(GridPosition::new(3, 3))).validate_placement(&[]), Ok(()));
+        let outside = GridPosition::new(FIELD_WIDTH, 0);
+        assert_eq!(teleporter(Some(outside)).validate_placement(&[]), Err(PlacementError::OutOfBounds(outside)));
+        let same = GridPosition::new(1, 1);
+        assert_eq!(teleporter(Some(same)).validate_placement(&[]), Err(PlacementError::Overlaps(same)));
+        let taken = GridPosition::new(3, 3);
+        assert_eq!(teleporter(Some(taken)).validate_placement(&[taken]), Err(PlacementError::Overlaps(taken)));
+    }
 }
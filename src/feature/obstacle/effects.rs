@@ -1,10 +1,18 @@
 use bevy::prelude::*;
 use rand::prelude::*;
 
-use crate::core::types::{GridPosition, world_to_grid, grid_to_world, is_valid_grid_position};
+use crate::core::types::{Direction, GridPosition, world_to_grid, grid_to_world, is_valid_grid_position};
 use crate::core::config::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT};
-use crate::feature::monster::{Monster, Movement, MonsterProperty};
-use super::components::{Obstacle, ObstaclePosition, SwampEffect, WindEffect, OnSwamp};
+use crate::core::GameRng;
+use crate::feature::monster::{
+    Monster, MonsterKind, Movement, MonsterProperty, CollisionState, CollisionBox, ExternalVelocity, MonsterSpawnQueue, MonsterLifecycleEvent,
+    LifecycleEvent, GoalBonuses, GoalBonus, BonusSource,
+};
+use super::definitions::TideAxis;
+use super::components::{
+    Obstacle, ObstacleKind, ObstaclePosition, ObstacleDirection, SwampEffect, WindEffect, WindMode, WindGust, WallEffect,
+    WallBehavior, ConveyorEffect, ConveyorMode, TeleporterEffect, ShrineEffect, Tide, InTide, OnSwamp, OnConveyor, InObstacle,
+};
 
 /// 風効果用のマーカー（同じモンスターが連続で風効果を受けないようにする）
 #[derive(Component, Debug)]
@@ -12,6 +20,15 @@ pub struct WindAffected {
     pub last_affected_pos: GridPosition,
 }
 
+/// ワープ効果用のマーカー（ワープ直後に同じマスで再びワープしないようにする）
+#[derive(Component, Debug)]
+pub struct Teleported {
+    /// ワープ先のマス
+    pub arrival_pos: GridPosition,
+    /// 再びワープできるようになるまでの残り時間（秒）
+    pub remaining: f32,
+}
+
 /// 風による押し出し移動中のコンポーネント
 #[derive(Component, Debug)]
 pub struct WindPush {
@@ -21,6 +38,66 @@ pub struct WindPush {
     pub duration: f32,
 }
 
+/// 障害物の出入り検出システム: モンスターがいるマスの障害物を追跡してInObstacleマーカーを更新し、
+/// 出入りをライフサイクルイベントとして通知する
+pub fn track_obstacle_occupancy_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    obstacle_query: Query<(Entity, &ObstaclePosition, &ObstacleKind), With<Obstacle>>,
+    monster_query: Query<(Entity, &MonsterKind, &Transform, Option<&InObstacle>), With<Monster>>,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
+) {
+    for (entity, kind, transform, in_obstacle) in &monster_query {
+        let position = transform.translation.xy();
+        let monster_grid_pos = world_to_grid(position, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+
+        let current = obstacle_query
+            .iter()
+            .find(|(_, obstacle_pos, _)| obstacle_pos.covers(monster_grid_pos))
+            .map(|(obstacle, _, obstacle_kind)| InObstacle {
+                obstacle,
+                kind: *obstacle_kind,
+            });
+
+        // 同じ障害物の上にいる場合は何もしない
+        if current.map(|c| c.obstacle) == in_obstacle.map(|c| c.obstacle) {
+            continue;
+        }
+
+        if let Some(previous) = in_obstacle {
+            lifecycle_events.write(MonsterLifecycleEvent::new(
+                entity,
+                *kind,
+                position,
+                time.elapsed_secs(),
+                LifecycleEvent::LeftObstacle {
+                    obstacle: previous.obstacle,
+                    obstacle_kind: previous.kind,
+                },
+            ));
+        }
+
+        match current {
+            Some(current) => {
+                lifecycle_events.write(MonsterLifecycleEvent::new(
+                    entity,
+                    *kind,
+                    position,
+                    time.elapsed_secs(),
+                    LifecycleEvent::EnteredObstacle {
+                        obstacle: current.obstacle,
+                        obstacle_kind: current.kind,
+                    },
+                ));
+                commands.entity(entity).insert(current);
+            }
+            None => {
+                commands.entity(entity).remove::<InObstacle>();
+            }
+        }
+    }
+}
+
 /// 泥沼検出システム: モンスターが泥沼の上にいるかチェックしてOnSwampマーカーを付与/削除
 pub fn detect_swamp_system(
     mut commands: Commands,
@@ -40,7 +117,7 @@ pub fn detect_swamp_system(
         let mut speed_multiplier = 1.0;
 
         for (obstacle_pos, swamp_effect) in &swamp_query {
-            if obstacle_pos.grid_pos == monster_grid_pos {
+            if obstacle_pos.covers(monster_grid_pos) {
                 is_on_swamp = true;
                 speed_multiplier = swamp_effect.speed_multiplier;
                 break;
@@ -78,18 +155,280 @@ pub fn apply_swamp_effect_system(
     }
 }
 
-/// 風効果: モンスターが風の上に来たとき、ランダムな方向に1マス飛ばす（0.2秒かけて移動）
-pub fn wind_effect_system(
+/// 潮効果: 潮の帯の中にいるモンスターの速度に倍率を掛け、押し戻しがある潮は帯の進む向きと逆向きの外部速度を与える
+///
+/// 泥沼効果の後に適用するので、泥沼と潮が重なると両方の倍率が掛かる。
+/// コンベアの上にいる間はコンベアの外部速度を優先し、潮では押し戻さない
+#[allow(clippy::type_complexity)]
+pub fn tide_effect_system(
+    mut commands: Commands,
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    tide_query: Query<&Tide>,
+    mut monster_query: Query<(Entity, &Transform, &mut Movement, Has<InTide>, Has<OnConveyor>), With<Monster>>,
+) {
+    // 潮の満ち引きはステージ開始からの経過時間で決まる
+    let Some(spawn_queue) = spawn_queue else {
+        return;
+    };
+
+    let bands: Vec<_> = tide_query
+        .iter()
+        .map(|tide| (&tide.definition, tide.definition.band(spawn_queue.timer)))
+        .collect();
+
+    for (entity, transform, mut movement, in_tide, on_conveyor) in &mut monster_query {
+        if !movement.enabled {
+            continue;
+        }
+
+        let mut push_back = Vec2::ZERO;
+        for (definition, (start, end)) in &bands {
+            // モンスターの位置を行または列の番号（小数）に変換
+            let position = transform.translation.xy();
+            let coordinate = match definition.axis {
+                TideAxis::Rows => position.y / GRID_SIZE + FIELD_HEIGHT as f32 / 2.0,
+                TideAxis::Columns => position.x / GRID_SIZE + FIELD_WIDTH as f32 / 2.0,
+            };
+
+            if (*start..*end).contains(&coordinate) {
+                movement.speed *= definition.speed_multiplier;
+                push_back += definition.push_back_velocity();
+            }
+        }
+
+        if on_conveyor {
+            continue;
+        }
+        if push_back != Vec2::ZERO {
+            commands.entity(entity).insert((
+                InTide,
+                ExternalVelocity {
+                    velocity: push_back,
+                    replace: false,
+                },
+            ));
+        } else if in_tide {
+            // 帯から出た → 押し戻しの外部速度を削除
+            commands.entity(entity).remove::<(InTide, ExternalVelocity)>();
+        }
+    }
+}
+
+/// コンベア効果: モンスターがコンベアの上にいる間、矢印の向きの外部速度を与える
+pub fn conveyor_effect_system(
+    mut commands: Commands,
+    conveyor_query: Query<(Entity, &ObstaclePosition, &ConveyorEffect), With<Obstacle>>,
+    monster_query: Query<(Entity, &Transform, Option<&OnConveyor>), With<Monster>>,
+) {
+    for (entity, transform, on_conveyor) in &monster_query {
+        let monster_grid_pos = world_to_grid(
+            transform.translation.xy(),
+            GRID_SIZE,
+            FIELD_WIDTH,
+            FIELD_HEIGHT,
+        );
+
+        let current = conveyor_query
+            .iter()
+            .find(|(_, obstacle_pos, _)| obstacle_pos.covers(monster_grid_pos));
+
+        match (current, on_conveyor) {
+            // 別のコンベアに乗り換えた場合も含めて、乗っているコンベアの速度に更新
+            (Some((conveyor, _, effect)), previous) if previous.map(|p| p.conveyor) != Some(conveyor) => {
+                commands.entity(entity).insert((
+                    OnConveyor { conveyor },
+                    ExternalVelocity {
+                        velocity: effect.direction.to_vector() * effect.speed,
+                        replace: effect.mode == ConveyorMode::Replace,
+                    },
+                ));
+            }
+            // コンベアから降りた → 外部速度を削除
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<(OnConveyor, ExternalVelocity)>();
+            }
+            // それ以外（状態が変わっていない）→ 何もしない
+            _ => {}
+        }
+    }
+}
+
+/// ワープ効果: モンスターがワープゲートに入ったら、進行方向とマス内での位置を保ったままもう一方の出入口に移動させる
+///
+/// ワープ後はクールダウンが終わり、かつ到着したマスを出るまで再びワープしない。
+/// 到着地点に別のモンスターがいて重なってしまう場合は、そのフレームはワープしない
+#[allow(clippy::type_complexity)]
+pub fn teleporter_effect_system(
     mut commands: Commands,
-    wind_query: Query<&ObstaclePosition, (With<Obstacle>, With<WindEffect>)>,
+    time: Res<Time>,
+    teleporter_query: Query<(&ObstaclePosition, &TeleporterEffect), With<Obstacle>>,
     mut monster_query: Query<
-        (Entity, &Transform, &mut Movement, Option<&WindAffected>, Option<&WindPush>),
+        (Entity, &MonsterKind, &mut Transform, &CollisionBox, Option<&mut Teleported>, Option<&WindPush>),
         With<Monster>
     >,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
 ) {
-    let mut rng = thread_rng();
+    // 到着地点の重なり判定に使うモンスターの位置（このフレームでワープしたモンスターは移動先に更新する）
+    let mut bodies: Vec<(Entity, Vec2, Vec2)> = monster_query
+        .iter()
+        .map(|(entity, _, transform, collision_box, _, _)| (entity, transform.translation.xy(), collision_box.size))
+        .collect();
+
+    for (entity, kind, mut transform, collision_box, teleported, wind_push) in &mut monster_query {
+        // 風で押し出されている途中はワープしない
+        if wind_push.is_some() {
+            continue;
+        }
+
+        let position = transform.translation.xy();
+        let monster_grid_pos = world_to_grid(position, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+
+        // クールダウン中、または到着したマスにいる間はワープしない
+        if let Some(mut teleported) = teleported {
+            teleported.remaining -= time.delta_secs();
+            if teleported.remaining > 0.0 || teleported.arrival_pos == monster_grid_pos {
+                continue;
+            }
+            commands.entity(entity).remove::<Teleported>();
+        }
+
+        let Some((obstacle_pos, teleporter)) = teleporter_query
+            .iter()
+            .find(|(obstacle_pos, _)| obstacle_pos.covers(monster_grid_pos))
+        else {
+            continue;
+        };
 
-    for (entity, transform, mut movement, wind_affected, wind_push) in &mut monster_query {
+        // 基準のマスからのずれ（複数マスを覆う場合はどのマスにいるかと、レーン上の位置）を保ったまま移動
+        let source = grid_to_world(obstacle_pos.grid_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+        let destination = grid_to_world(teleporter.destination, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+        let new_pos = destination + (position - source);
+        let blocked = bodies.iter().any(|(other, other_pos, other_size)| {
+            let reach = (collision_box.size + *other_size) / 2.0;
+            let distance = (new_pos - *other_pos).abs();
+            *other != entity && distance.x < reach.x && distance.y < reach.y
+        });
+        if blocked {
+            continue;
+        }
+        if let Some(body) = bodies.iter_mut().find(|(other, _, _)| *other == entity) {
+            body.1 = new_pos;
+        }
+
+        transform.translation.x = new_pos.x;
+        transform.translation.y = new_pos.y;
+        let arrival_pos = world_to_grid(new_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+
+        commands.entity(entity).insert(Teleported {
+            arrival_pos,
+            remaining: teleporter.cooldown,
+        });
+
+        lifecycle_events.write(MonsterLifecycleEvent::new(
+            entity,
+            *kind,
+            new_pos,
+            time.elapsed_secs(),
+            LifecycleEvent::Teleported {
+                from: monster_grid_pos,
+                to: arrival_pos,
+            },
+        ));
+    }
+}
+
+/// 祠効果: 祠のそばを通ったモンスターに、ゴール到達時のボーナスを付与する（同じ祠からは1回だけ）
+pub fn shrine_effect_system(
+    mut commands: Commands,
+    shrine_query: Query<(Entity, &ObstaclePosition, &ShrineEffect), With<Obstacle>>,
+    mut monster_query: Query<(Entity, &Transform, Option<&mut GoalBonuses>), With<Monster>>,
+) {
+    for (entity, transform, mut goal_bonuses) in &mut monster_query {
+        let monster_grid_pos = world_to_grid(
+            transform.translation.xy(),
+            GRID_SIZE,
+            FIELD_WIDTH,
+            FIELD_HEIGHT,
+        );
+
+        // 画面端で待機中のモンスターはフィールドの外にいるので対象外
+        if !is_valid_grid_position(monster_grid_pos, FIELD_WIDTH, FIELD_HEIGHT) {
+            continue;
+        }
+
+        let mut new_bonuses = Vec::new();
+        for (shrine, obstacle_pos, shrine_effect) in &shrine_query {
+            let source = BonusSource::Shrine { obstacle: shrine, grid_pos: obstacle_pos.grid_pos };
+            let already_blessed = goal_bonuses
+                .as_ref()
+                .is_some_and(|goal_bonuses| goal_bonuses.0.iter().any(|bonus| bonus.source == source));
+
+            if !already_blessed && obstacle_pos.is_within(monster_grid_pos, shrine_effect.radius) {
+                new_bonuses.push(GoalBonus { source, effect: shrine_effect.bonus });
+            }
+        }
+
+        if new_bonuses.is_empty() {
+            continue;
+        }
+
+        match goal_bonuses.as_mut() {
+            Some(goal_bonuses) => goal_bonuses.0.extend(new_bonuses),
+            None => {
+                commands.entity(entity).insert(GoalBonuses(new_bonuses));
+            }
+        }
+    }
+}
+
+/// 突風: Rotatingモードの風の向きをinterval秒ごとに順番に切り替える
+pub fn wind_gust_system(
+    time: Res<Time>,
+    mut wind_query: Query<(&WindEffect, &mut WindGust, &mut ObstacleDirection), With<Obstacle>>,
+) {
+    for (wind_effect, mut gust, mut obstacle_direction) in &mut wind_query {
+        let WindMode::Rotating { directions, interval } = &wind_effect.mode else {
+            continue;
+        };
+        if directions.is_empty() || *interval <= 0.0 {
+            continue;
+        }
+
+        gust.elapsed += time.delta_secs();
+        while gust.elapsed >= *interval {
+            gust.elapsed -= *interval;
+            gust.index = (gust.index + 1) % directions.len();
+        }
+        obstacle_direction.0 = directions[gust.index];
+    }
+}
+
+/// 風効果: モンスターが風の上に来たとき、風のモードで決まる向きにpush_distanceマス飛ばす（push_duration秒かけて移動）
+///
+/// フィールドの外や壁に向かって1マスも進めない向きには飛ばさない。
+/// turn_headingが有効な風は、飛ばした向きにモンスターの進行方向も変える
+#[allow(clippy::too_many_arguments)]
+pub fn wind_effect_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    wind_query: Query<(&ObstaclePosition, &WindEffect, Option<&ObstacleDirection>), With<Obstacle>>,
+    wall_query: Query<&ObstaclePosition, (With<Obstacle>, With<WallEffect>)>,
+    mut monster_query: Query<
+        (
+            Entity,
+            &MonsterKind,
+            &Transform,
+            &mut Movement,
+            &mut MonsterProperty,
+            Option<&WindAffected>,
+            Option<&WindPush>,
+        ),
+        With<Monster>
+    >,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
+    mut rng: ResMut<GameRng>,
+) {
+    for (entity, kind, transform, mut movement, mut property, wind_affected, wind_push) in &mut monster_query {
         // 既にWindPush中の場合はスキップ
         if wind_push.is_some() {
             continue;
@@ -110,54 +449,137 @@ pub fn wind_effect_system(
         }
 
         // 風の上にいるかチェック
-        for obstacle_pos in &wind_query {
-            if obstacle_pos.grid_pos == monster_grid_pos {
-                // ランダムな方向に1マス飛ばす
-                let directions = [
-                    GridPosition { x: 1, y: 0 },   // 右
-                    GridPosition { x: -1, y: 0 },  // 左
-                    GridPosition { x: 0, y: 1 },   // 上
-                    GridPosition { x: 0, y: -1 },  // 下
-                ];
-
-                let random_direction = directions.choose(&mut rng).unwrap();
-                let new_grid_pos = GridPosition {
-                    x: monster_grid_pos.x + random_direction.x,
-                    y: monster_grid_pos.y + random_direction.y,
-                };
-
-                // 新しい位置がフィールド範囲内かチェック
-                if is_valid_grid_position(new_grid_pos, FIELD_WIDTH, FIELD_HEIGHT) {
-                    let new_world_pos = grid_to_world(
-                        new_grid_pos,
-                        GRID_SIZE,
-                        FIELD_WIDTH,
-                        FIELD_HEIGHT,
-                    );
-
-                    // WindPushコンポーネントを追加して補間移動を開始
-                    commands.entity(entity).insert(WindPush {
-                        start_pos: transform.translation.xy(),
-                        target_pos: new_world_pos,
-                        elapsed: 0.0,
-                        duration: crate::core::level::WIND_PUSH_DURATION,
-                    });
-
-                    // 通常の移動を停止
-                    movement.enabled = false;
-
-                    // WindAffectedマーカーを更新または追加
-                    commands.entity(entity).insert(WindAffected {
-                        last_affected_pos: monster_grid_pos,
-                    });
-                }
+        for (obstacle_pos, wind_effect, obstacle_direction) in &wind_query {
+            if !obstacle_pos.covers(monster_grid_pos) {
+                continue;
+            }
 
-                break;
+            // 押し出す向きの候補（突風は現在の向きだけ）
+            let candidates: Vec<Direction> = match &wind_effect.mode {
+                WindMode::Fixed(direction) => vec![*direction],
+                WindMode::Random { allowed } => allowed.clone(),
+                WindMode::Rotating { .. } => obstacle_direction.map(|direction| vec![direction.0]).unwrap_or_default(),
+            };
+
+            // 向きごとに、フィールド範囲内に収まる最も遠いマスを求める（壁は越えられない）
+            let landings: Vec<(Direction, GridPosition)> = candidates
+                .into_iter()
+                .filter_map(|direction| {
+                    let step = direction.to_vector().round().as_ivec2();
+                    (1..=wind_effect.push_distance)
+                        .map(|distance| GridPosition {
+                            x: monster_grid_pos.x + step.x * distance,
+                            y: monster_grid_pos.y + step.y * distance,
+                        })
+                        .take_while(|grid_pos| {
+                            is_valid_grid_position(*grid_pos, FIELD_WIDTH, FIELD_HEIGHT)
+                                && !wall_query.iter().any(|wall_pos| wall_pos.covers(*grid_pos))
+                        })
+                        .last()
+                        .map(|landing| (direction, landing))
+                })
+                .collect();
+
+            if let Some(&(push_direction, new_grid_pos)) = landings.choose(&mut *rng) {
+                let new_world_pos = grid_to_world(
+                    new_grid_pos,
+                    GRID_SIZE,
+                    FIELD_WIDTH,
+                    FIELD_HEIGHT,
+                );
+
+                // WindPushコンポーネントを追加して補間移動を開始
+                commands.entity(entity).insert(WindPush {
+                    start_pos: transform.translation.xy(),
+                    target_pos: new_world_pos,
+                    elapsed: 0.0,
+                    duration: wind_effect.push_duration,
+                });
+
+                // 通常の移動を停止
+                movement.enabled = false;
+
+                // WindAffectedマーカーを更新または追加
+                commands.entity(entity).insert(WindAffected {
+                    last_affected_pos: monster_grid_pos,
+                });
+
+                lifecycle_events.write(MonsterLifecycleEvent::new(
+                    entity,
+                    *kind,
+                    transform.translation.xy(),
+                    time.elapsed_secs(),
+                    LifecycleEvent::WindPushed {
+                        from: monster_grid_pos,
+                        to: new_grid_pos,
+                    },
+                ));
+
+                // 押し出した向きに進行方向を変える（本来の方向も変えるので、着地後もその向きに進む）
+                if wind_effect.turn_heading && movement.direction != push_direction {
+                    let previous_direction = movement.direction;
+                    property.base_direction = push_direction;
+                    movement.direction = push_direction;
+
+                    lifecycle_events.write(MonsterLifecycleEvent::new(
+                        entity,
+                        *kind,
+                        transform.translation.xy(),
+                        time.elapsed_secs(),
+                        LifecycleEvent::DirectionChanged {
+                            from: previous_direction,
+                            to: push_direction,
+                        },
+                    ));
+                }
             }
+
+            break;
         }
     }
 }
 
+/// 壁効果: 前フレームに壁にぶつかったモンスターを、壁の設定に応じて右折またはUターンさせる
+///
+/// 本来の方向そのものを変更するので、曲がった後もその方向に進み続ける
+#[allow(clippy::type_complexity)]
+pub fn wall_turn_system(
+    time: Res<Time>,
+    wall_query: Query<&WallEffect, With<Obstacle>>,
+    mut monster_query: Query<
+        (Entity, &MonsterKind, &Transform, &CollisionState, &mut MonsterProperty, &mut Movement),
+        With<Monster>
+    >,
+    mut lifecycle_events: MessageWriter<MonsterLifecycleEvent>,
+) {
+    for (entity, kind, transform, collision_state, mut property, mut movement) in &mut monster_query {
+        let Some(wall) = collision_state.blocked_by.and_then(|blocker| wall_query.get(blocker).ok()) else {
+            continue;
+        };
+
+        let new_direction = match wall.behavior {
+            WallBehavior::Stop => continue,
+            WallBehavior::TurnRight => movement.direction.rotate_clockwise(),
+            WallBehavior::TurnBack => movement.direction.opposite(),
+        };
+
+        let previous_direction = movement.direction;
+        property.base_direction = new_direction;
+        movement.direction = new_direction;
+
+        lifecycle_events.write(MonsterLifecycleEvent::new(
+            entity,
+            *kind,
+            transform.translation.xy(),
+            time.elapsed_secs(),
+            LifecycleEvent::DirectionChanged {
+                from: previous_direction,
+                to: new_direction,
+            },
+        ));
+    }
+}
+
 /// 風による押し出し移動を処理するシステム
 pub fn wind_push_system(
     mut commands: Commands,
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use crate::core::GridPosition;
+use super::components::ObstacleKind;
+
+/// 障害物が取り除かれたイベント
+#[derive(Message, Debug, Clone)]
+pub struct ObstacleClearedEvent {
+    /// 取り除かれた障害物のエンティティ（消えるアニメーションが終わるまでは残っている）
+    pub entity: Entity,
+    /// 取り除かれた障害物の種類
+    pub kind: ObstacleKind,
+    /// 取り除かれた障害物の基準のマス
+    pub grid_pos: GridPosition,
+    /// 取り除かれた障害物が覆っていたすべてのマス
+    pub cells: Vec<GridPosition>,
+}
@@ -1,10 +1,16 @@
+mod clearing;
 mod components;
 mod definitions;
 mod effects;
+mod events;
 mod spawn;
+mod wave_events;
 mod plugin;
 
 pub use components::*;
 pub use definitions::*;
 pub use effects::*;
+pub use events::*;
+pub use spawn::spawn_obstacle;
+pub use wave_events::{ObstacleEventQueue, ScheduledObstacleEvent};
 pub use plugin::ObstaclePlugin;
@@ -1,30 +1,106 @@
 use bevy::prelude::*;
 use crate::GameState;
+use crate::core::ObstacleCatalogAsset;
+use super::definitions::{ObstacleCatalog, ObstacleCatalogLoader};
+use crate::feature::ui::{
+    draw_obstacle_direction_system, draw_teleporter_link_system, update_tide_overlay_system, draw_obstacle_event_telegraph_system,
+    draw_shrine_area_system,
+};
 
+use super::events::ObstacleClearedEvent;
 use super::spawn::spawn_obstacles_from_stage;
-use super::effects::{detect_swamp_system, apply_swamp_effect_system, wind_effect_system, wind_push_system};
+use super::wave_events::{ObstacleEventQueue, schedule_obstacle_events_system, run_obstacle_events_system};
+use super::clearing::{clear_obstacle_on_click_system, cleanup_cleared_obstacle_markers_system, obstacle_clearing_animation_system};
+use super::effects::{track_obstacle_occupancy_system, detect_swamp_system, apply_swamp_effect_system, wind_gust_system, wind_effect_system, wind_push_system, wall_turn_system, conveyor_effect_system, teleporter_effect_system, tide_effect_system, shrine_effect_system};
 
 pub struct ObstaclePlugin;
 
 impl Plugin for ObstaclePlugin {
     fn build(&self, app: &mut App) {
         app
-            // Update: ステージアセットから障害物をスポーン
-            .add_systems(Update, spawn_obstacles_from_stage)
+            .init_resource::<ObstacleCatalog>()
+            .init_resource::<ObstacleEventQueue>()
+            .add_message::<ObstacleClearedEvent>()
+            .add_systems(Startup, load_obstacle_catalog_system)
+            // Update: 障害物カタログとステージアセットから障害物をスポーン
+            .add_systems(Update, (initialize_obstacle_catalog_system, spawn_obstacles_from_stage).chain())
+            // Update: 右クリックで障害物を削り、Wave中の障害物イベントを実行する
+            // （取り除かれた障害物の効果は同じフレームから無くなる）
+            .add_systems(
+                Update,
+                (
+                    clear_obstacle_on_click_system,
+                    schedule_obstacle_events_system,
+                    run_obstacle_events_system,
+                    cleanup_cleared_obstacle_markers_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame))
+                    .before(track_obstacle_occupancy_system)
+            )
             // Update: 効果の適用
             // 障害物効果は衝突検出の前に適用する必要がある
             .add_systems(
                 Update,
                 (
+                    track_obstacle_occupancy_system, // 障害物の出入り検出
                     detect_swamp_system,        // 泥沼検出（OnSwampマーカーの付与/削除）
                     apply_swamp_effect_system,  // 泥沼効果適用
+                    tide_effect_system,         // 潮効果適用（泥沼の速度にさらに倍率を掛ける）
+                    conveyor_effect_system,     // コンベア検出（外部速度の付与/削除）
+                    teleporter_effect_system,   // ワープ
+                    shrine_effect_system,       // 祠のボーナス付与
+                    wind_gust_system,           // 突風の向きの切り替え
                     wind_effect_system,         // 風検出
                     wind_push_system,           // 風押し出し
+                    wall_turn_system,           // 壁での方向転換（前フレームの衝突結果を使用）
                 )
                     .chain()
                     .run_if(in_state(GameState::InGame))
                     .before(crate::feature::monster::collision::collision_detection_system)
+            )
+            // 一方通行ゲートとコンベアの向き、ワープゲートのつながり、潮の帯、取り除かれた障害物のアニメーション、
+            // 障害物イベントの予告、祠のご利益の範囲を表示
+            .add_systems(
+                Update,
+                (
+                    draw_obstacle_direction_system,
+                    draw_teleporter_link_system,
+                    update_tide_overlay_system,
+                    obstacle_clearing_animation_system,
+                    draw_obstacle_event_telegraph_system,
+                    draw_shrine_area_system,
+                )
+                    .run_if(in_state(GameState::InGame))
             );
     }
 }
 
+
+/// 障害物カタログをロードするシステム（起動時に一度だけ実行）
+fn load_obstacle_catalog_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let handle: Handle<ObstacleCatalogAsset> = asset_server.load("obstacles.ron");
+    commands.insert_resource(ObstacleCatalogLoader {
+        handle,
+        loaded: false,
+    });
+}
+
+/// 障害物カタログが読み込まれたらObstacleCatalogを初期化するシステム
+fn initialize_obstacle_catalog_system(
+    mut loader: ResMut<ObstacleCatalogLoader>,
+    catalog_assets: Res<Assets<ObstacleCatalogAsset>>,
+    mut catalog: ResMut<ObstacleCatalog>,
+) {
+    // すでにロード済みならスキップ
+    if loader.loaded {
+        return;
+    }
+
+    // アセットがロードされたか確認
+    if let Some(catalog_asset) = catalog_assets.get(&loader.handle) {
+        *catalog = ObstacleCatalog::from_entries(catalog_asset.obstacles.clone());
+        loader.loaded = true;
+        info!("Obstacle catalog loaded: {} types", catalog_asset.obstacles.len());
+    }
+}
@@ -1,16 +1,19 @@
 use bevy::prelude::*;
 use crate::core::stage_asset::StageLevelAsset;
-use crate::core::config::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT};
-use crate::core::types::grid_to_world;
-use crate::feature::monster::StageLevelLoader;
+use crate::core::config::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, TIDE_COLOR};
+use crate::core::types::{grid_to_world, Direction, GridPosition};
+use crate::feature::monster::{BonusEffect, StageLevelLoader, StaticCollider};
 use super::components::*;
-use super::definitions::ObstacleVisualConfig;
+use super::definitions::{ObstacleCatalog, ObstacleCatalogLoader, ObstacleDefinition};
 
 /// ステージアセットから障害物をスポーン
 pub fn spawn_obstacles_from_stage(
     mut commands: Commands,
     stage_assets: Res<Assets<StageLevelAsset>>,
     stage_loader: Res<StageLevelLoader>,
+    catalog: Res<ObstacleCatalog>,
+    catalog_loader: Res<ObstacleCatalogLoader>,
+    asset_server: Res<AssetServer>,
     mut spawned: Local<bool>,
 ) {
     // 既にスポーン済みならスキップ
@@ -18,8 +21,8 @@ pub fn spawn_obstacles_from_stage(
         return;
     }
 
-    // ステージがロードされていなければスキップ
-    if !stage_loader.loaded {
+    // ステージと障害物カタログがロードされていなければスキップ
+    if !stage_loader.loaded || !catalog_loader.loaded {
         return;
     }
 
@@ -29,38 +32,244 @@ pub fn spawn_obstacles_from_stage(
 
     info!("Spawning {} obstacles from stage", stage_asset.obstacles.len());
 
+    // フィールドからはみ出す配置や、先に置いた障害物と重なる配置は置かない
+    let mut occupied: Vec<GridPosition> = Vec::new();
     for obstacle_def in &stage_asset.obstacles {
-        let config = ObstacleVisualConfig::get_config(obstacle_def.kind);
-        let world_pos = grid_to_world(
-            obstacle_def.grid_pos,
-            GRID_SIZE,
-            FIELD_WIDTH,
-            FIELD_HEIGHT,
-        );
-
-        let mut entity_commands = commands.spawn((
-            Obstacle,
-            obstacle_def.kind,
-            ObstaclePosition { grid_pos: obstacle_def.grid_pos },
+        if let Err(error) = obstacle_def.validate_placement(&occupied) {
+            warn!("Obstacle {:?} at {:?} skipped: {:?}", obstacle_def.kind, obstacle_def.grid_pos, error);
+            continue;
+        }
+        occupied.extend(obstacle_def.occupied_cells());
+        spawn_obstacle(&mut commands, obstacle_def, &catalog, &asset_server);
+    }
+
+    // 潮の帯（位置と大きさは毎フレーム更新される）
+    for tide_def in &stage_asset.tides {
+        if let Err(error) = tide_def.validate() {
+            warn!("Tide {:?} skipped: {:?}", tide_def.axis, error);
+            continue;
+        }
+        commands.spawn((
+            Tide { definition: tide_def.clone() },
             Sprite {
-                color: Color::srgb(config.color.0, config.color.1, config.color.2),
-                custom_size: Some(Vec2::splat(GRID_SIZE * config.size)),
+                color: Color::srgba(TIDE_COLOR.0, TIDE_COLOR.1, TIDE_COLOR.2, TIDE_COLOR.3),
+                custom_size: Some(Vec2::ZERO),
                 ..default()
             },
-            Transform::from_translation(world_pos.extend(0.0)),
+            Transform::from_translation(Vec3::new(0.0, 0.0, 0.5)),
         ));
+    }
+
+    // スポーン完了をマーク
+    *spawned = true;
+}
+
+/// 障害物を1つスポーン
+///
+/// 見た目と効果パラメータはカタログの値に、配置ごとのparamsを上書きして決める。
+/// ワープゲートはlinkのマスにもう一方の出入口をスポーンし、最初の出入口のエンティティを返す
+pub fn spawn_obstacle(
+    commands: &mut Commands,
+    obstacle_def: &ObstacleDefinition,
+    catalog: &ObstacleCatalog,
+    asset_server: &AssetServer,
+) -> Entity {
+    let entity = spawn_single_obstacle(commands, obstacle_def, catalog, asset_server);
+
+    if let (ObstacleKind::Teleporter, Some(link)) = (obstacle_def.kind, obstacle_def.link) {
+        let partner = ObstacleDefinition {
+            grid_pos: link,
+            link: Some(obstacle_def.grid_pos),
+            ..obstacle_def.clone()
+        };
+        spawn_single_obstacle(commands, &partner, catalog, asset_server);
+    }
+
+    entity
+}
 
-        // 種類に応じた効果コンポーネントを追加
-        match obstacle_def.kind {
-            ObstacleKind::Swamp => {
-                entity_commands.insert(SwampEffect::default());
+/// 障害物のエンティティを1つスポーン
+fn spawn_single_obstacle(
+    commands: &mut Commands,
+    obstacle_def: &ObstacleDefinition,
+    catalog: &ObstacleCatalog,
+    asset_server: &AssetServer,
+) -> Entity {
+    let entry = catalog.get(obstacle_def.kind);
+    let params = entry.params.merged_with(&obstacle_def.params);
+    let direction = obstacle_def.direction.unwrap_or(Direction::Right);
+    let mut cells = obstacle_def.cells();
+    if cells.is_empty() {
+        // 形の指定が空の場合は基準のマスだけを覆う
+        cells.push(obstacle_def.grid_pos);
+    }
+    let layout = FootprintLayout::new(&cells);
+
+    // テクスチャがあればテクスチャ、なければフォールバックの色で表示
+    // （1マスより小さく表示する分の余白は、複数マスを覆う場合も外周にだけ付ける）
+    let sprite_size = layout.tile_size - Vec2::splat(GRID_SIZE * (1.0 - entry.size));
+    let sprite = match &entry.texture_path {
+        Some(texture_path) => Sprite {
+            image: asset_server.load(texture_path),
+            custom_size: Some(sprite_size),
+            ..default()
+        },
+        None => Sprite {
+            color: Color::srgb(entry.color.0, entry.color.1, entry.color.2),
+            custom_size: Some(sprite_size),
+            ..default()
+        },
+    };
+
+    let mut entity_commands = commands.spawn((
+        Obstacle,
+        obstacle_def.kind,
+        ObstaclePosition { grid_pos: obstacle_def.grid_pos, cells },
+        sprite.clone(),
+        Transform::from_translation(layout.center.extend(0.0)),
+    ));
+
+    // 長方形にならない形は、基準以外のマスを子エンティティで表示する
+    if layout.offsets.len() > 1 {
+        entity_commands.with_children(|parent| {
+            for offset in &layout.offsets[1..] {
+                parent.spawn((
+                    ObstacleCellSprite,
+                    sprite.clone(),
+                    Transform::from_translation(offset.extend(0.0)),
+                ));
             }
-            ObstacleKind::Wind => {
-                entity_commands.insert(WindEffect);
+        });
+    }
+
+    // 耐久値が指定されていれば取り除けるようにする
+    if let Some(durability) = params.durability.filter(|durability| *durability > 0) {
+        entity_commands.insert(Destructible {
+            durability,
+            remaining: durability,
+            clear_cost: params.clear_cost.unwrap_or(crate::core::level::OBSTACLE_CLEAR_COST),
+        });
+    }
+
+    // 種類に応じた効果コンポーネントを追加
+    match obstacle_def.kind {
+        ObstacleKind::Swamp => {
+            let default = SwampEffect::default();
+            entity_commands.insert(SwampEffect {
+                speed_multiplier: params.speed_multiplier.unwrap_or(default.speed_multiplier),
+            });
+        }
+        ObstacleKind::Wind => {
+            let default = WindEffect::default();
+            let mode = params.wind_mode.clone().unwrap_or(default.mode);
+
+            // 向きが決まっている風は矢印で向きを表示する（突風は切り替わるたびに矢印も変わる）
+            match &mode {
+                WindMode::Fixed(direction) => {
+                    entity_commands.insert(ObstacleDirection(*direction));
+                }
+                WindMode::Rotating { directions, .. } => match directions.first() {
+                    Some(first) => {
+                        entity_commands.insert((ObstacleDirection(*first), WindGust::default()));
+                    }
+                    None => {
+                        warn!("Rotating wind at {:?} has no directions and will not push", obstacle_def.grid_pos);
+                    }
+                },
+                WindMode::Random { .. } => {}
             }
+
+            entity_commands.insert(WindEffect {
+                mode,
+                push_distance: params.push_distance.unwrap_or(default.push_distance),
+                push_duration: params.push_duration.unwrap_or(default.push_duration),
+                turn_heading: params.turn_heading.unwrap_or(default.turn_heading),
+            });
+        }
+        ObstacleKind::Wall => {
+            // 壁はマス全体をふさぐ
+            entity_commands.insert((
+                WallEffect {
+                    behavior: params.wall_behavior.unwrap_or_default(),
+                },
+                StaticCollider { size: layout.tile_size, offsets: layout.offsets.clone(), one_way: None },
+            ));
+        }
+        ObstacleKind::OneWay => {
+            // 一方通行ゲートはマス全体を、矢印と逆向きに進入するモンスターに対してだけふさぐ
+            entity_commands.insert((
+                OneWayEffect { direction },
+                ObstacleDirection(direction),
+                StaticCollider { size: layout.tile_size, offsets: layout.offsets.clone(), one_way: Some(direction) },
+            ));
         }
+        ObstacleKind::Conveyor => {
+            entity_commands.insert((
+                ConveyorEffect {
+                    direction,
+                    speed: params.conveyor_speed.unwrap_or(crate::core::level::CONVEYOR_SPEED),
+                    mode: params.conveyor_mode.unwrap_or_default(),
+                },
+                ObstacleDirection(direction),
+            ));
+        }
+        ObstacleKind::Shrine => {
+            entity_commands.insert(ShrineEffect {
+                radius: params.shrine_radius.unwrap_or(crate::core::level::SHRINE_RADIUS),
+                bonus: params.shrine_bonus.unwrap_or(BonusEffect::Spirit(crate::core::level::SHRINE_SPIRIT_BONUS)),
+            });
+        }
+        ObstacleKind::Teleporter => match obstacle_def.link {
+            Some(destination) => {
+                entity_commands.insert(TeleporterEffect {
+                    destination,
+                    cooldown: params.teleport_cooldown.unwrap_or(crate::core::level::TELEPORT_COOLDOWN),
+                });
+            }
+            None => {
+                warn!("Teleporter at {:?} has no link and will not teleport", obstacle_def.grid_pos);
+            }
+        },
     }
 
-    // スポーン完了をマーク
-    *spawned = true;
+    entity_commands.id()
+}
+
+/// 障害物が覆うマスの表示とコライダーの並べ方
+struct FootprintLayout {
+    /// 障害物のエンティティの位置
+    center: Vec2,
+    /// 1枚のスプライト（コライダーの箱）が覆う大きさ
+    tile_size: Vec2,
+    /// 各スプライト（コライダーの箱）の中心の、centerからの相対位置
+    offsets: Vec<Vec2>,
+}
+
+impl FootprintLayout {
+    /// 長方形に並んだマスは1枚で覆い、それ以外はマスごとに並べる
+    fn new(cells: &[GridPosition]) -> Self {
+        let min_x = cells.iter().map(|cell| cell.x).min().unwrap_or(0);
+        let max_x = cells.iter().map(|cell| cell.x).max().unwrap_or(0);
+        let min_y = cells.iter().map(|cell| cell.y).min().unwrap_or(0);
+        let max_y = cells.iter().map(|cell| cell.y).max().unwrap_or(0);
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+
+        let to_world = |cell: GridPosition| grid_to_world(cell, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+
+        if cells.len() as i32 == width * height {
+            Self {
+                center: (to_world(GridPosition::new(min_x, min_y)) + to_world(GridPosition::new(max_x, max_y))) / 2.0,
+                tile_size: Vec2::new(width as f32, height as f32) * GRID_SIZE,
+                offsets: vec![Vec2::ZERO],
+            }
+        } else {
+            let center = to_world(cells[0]);
+            Self {
+                center,
+                tile_size: Vec2::splat(GRID_SIZE),
+                offsets: cells.iter().map(|cell| to_world(*cell) - center).collect(),
+            }
+        }
+    }
 }
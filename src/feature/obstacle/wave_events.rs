@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use crate::core::types::{GridPosition, grid_to_world};
+use crate::core::config::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT};
+use crate::core::level;
+use crate::feature::monster::{Monster, CollisionBox, MonsterSpawnQueue};
+use super::clearing::clear_obstacle;
+use super::components::{Obstacle, ObstacleKind, ObstaclePosition, TeleporterEffect};
+use super::definitions::{ObstacleAction, ObstacleCatalog};
+use super::events::ObstacleClearedEvent;
+use super::spawn::spawn_obstacle;
+
+/// 実行時刻が決まった障害物イベント
+#[derive(Debug, Clone)]
+pub struct ScheduledObstacleEvent {
+    /// 実行する時刻（ゲーム開始からの経過時間・秒）
+    pub time: f32,
+    /// イベントを持っていたWaveのインデックス
+    pub wave: usize,
+    pub action: ObstacleAction,
+    /// 取り除く障害物（Waveで置いた障害物の撤去では、マスではなく置いた障害物そのものを取り除く）
+    pub target: Option<Entity>,
+}
+
+/// Wave中の障害物イベントのキュー（リソース）
+#[derive(Resource, Debug, Default)]
+pub struct ObstacleEventQueue {
+    /// 実行待ちのイベント
+    pub scheduled: Vec<ScheduledObstacleEvent>,
+    /// イベントをキューに追加済みのWave
+    scheduled_waves: Vec<usize>,
+    /// Waveのモンスターがいなくなったら取り除く障害物（Waveのインデックス、置いた障害物、そのマスの組）
+    removals_on_wave_end: Vec<(usize, Entity, GridPosition)>,
+}
+
+impl ObstacleEventQueue {
+    /// 予告時間内に実行される予定のイベントと、実行までの残り時間を返す
+    pub fn upcoming(&self, timer: f32) -> impl Iterator<Item = (&ScheduledObstacleEvent, f32)> {
+        self.scheduled
+            .iter()
+            .map(move |event| (event, (event.time - timer).max(0.0)))
+            .filter(|(_, remaining)| *remaining <= level::OBSTACLE_EVENT_TELEGRAPH_TIME)
+    }
+}
+
+/// 開始したWaveの障害物イベントと、片付いたWaveの障害物の撤去をキューに追加するシステム
+///
+/// イベントはステージファイルの指定どおりの時刻に実行する。
+/// Wave開始からの遅延が予告時間より短いイベントは予告がその分短くなるので警告を出す
+pub fn schedule_obstacle_events_system(
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    mut event_queue: ResMut<ObstacleEventQueue>,
+) {
+    let Some(spawn_queue) = spawn_queue else {
+        return;
+    };
+
+    for (&wave, &start_time) in &spawn_queue.wave_start_times {
+        if event_queue.scheduled_waves.contains(&wave) {
+            continue;
+        }
+        event_queue.scheduled_waves.push(wave);
+
+        for obstacle_event in &spawn_queue.waves[wave].obstacle_events {
+            if obstacle_event.delay < level::OBSTACLE_EVENT_TELEGRAPH_TIME {
+                warn!(
+                    "Wave {} obstacle event delay {:.1}s is shorter than the telegraph time {:.1}s",
+                    wave,
+                    obstacle_event.delay,
+                    level::OBSTACLE_EVENT_TELEGRAPH_TIME
+                );
+            }
+            event_queue.scheduled.push(ScheduledObstacleEvent {
+                time: start_time + obstacle_event.delay,
+                wave,
+                action: obstacle_event.action.clone(),
+                target: None,
+            });
+        }
+    }
+
+    // 片付いたWaveで置いた障害物を、予告時間の後に取り除く
+    let removal_time = spawn_queue.timer + level::OBSTACLE_EVENT_TELEGRAPH_TIME;
+    let (cleared, remaining): (Vec<_>, Vec<_>) = std::mem::take(&mut event_queue.removals_on_wave_end)
+        .into_iter()
+        .partition(|(wave, _, _)| spawn_queue.wave_cleared_times.contains_key(wave));
+    event_queue.removals_on_wave_end = remaining;
+
+    for (wave, entity, grid_pos) in cleared {
+        event_queue.scheduled.push(ScheduledObstacleEvent {
+            time: removal_time,
+            wave,
+            action: ObstacleAction::Remove { grid_pos },
+            target: Some(entity),
+        });
+    }
+}
+
+/// 実行時刻になった障害物イベントを実行するシステム
+///
+/// 壁を置くマスにモンスターがいる場合は、閉じ込めないようにマスが空くまで置くのを待つ
+#[allow(clippy::too_many_arguments)]
+pub fn run_obstacle_events_system(
+    mut commands: Commands,
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    mut event_queue: ResMut<ObstacleEventQueue>,
+    catalog: Res<ObstacleCatalog>,
+    asset_server: Res<AssetServer>,
+    obstacle_query: Query<(Entity, &ObstacleKind, &ObstaclePosition, Option<&TeleporterEffect>), With<Obstacle>>,
+    monster_query: Query<(&Transform, &CollisionBox), With<Monster>>,
+    mut cleared_events: MessageWriter<ObstacleClearedEvent>,
+) {
+    let Some(spawn_queue) = spawn_queue else {
+        return;
+    };
+
+    let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut event_queue.scheduled)
+        .into_iter()
+        .partition(|event| event.time <= spawn_queue.timer);
+    event_queue.scheduled = pending;
+
+    // 障害物があるマス（このフレームで置いた分は、コマンドが反映されるまでクエリに現れないので追加していく）
+    let mut occupied: Vec<GridPosition> = obstacle_query
+        .iter()
+        .flat_map(|(_, _, obstacle_pos, _)| obstacle_pos.cells.iter().copied())
+        .collect();
+    // このフレームで取り除いた障害物（複数マスを覆う障害物を二重に取り除かないようにする）
+    let mut removed: Vec<Entity> = Vec::new();
+
+    for event in due {
+        match &event.action {
+            ObstacleAction::Place { obstacle, remove_on_wave_end } => {
+                if let Err(error) = obstacle.validate_placement(&occupied) {
+                    warn!("Obstacle event skipped: {:?} at {:?}: {:?}", obstacle.kind, obstacle.grid_pos, error);
+                    continue;
+                }
+
+                // 壁は中にいるモンスターを閉じ込めてしまうので、マスが空くまで次のフレーム以降に持ち越す
+                let cells = obstacle.occupied_cells();
+                if obstacle.kind == ObstacleKind::Wall && is_any_monster_on_cells(&cells, &monster_query) {
+                    event_queue.scheduled.push(event);
+                    continue;
+                }
+
+                let entity = spawn_obstacle(&mut commands, obstacle, &catalog, &asset_server);
+                info!("Wave {} placed {:?} at {:?}", event.wave, obstacle.kind, obstacle.grid_pos);
+
+                occupied.extend(cells.iter().copied());
+                if *remove_on_wave_end {
+                    event_queue.removals_on_wave_end.push((event.wave, entity, obstacle.grid_pos));
+                }
+            }
+            ObstacleAction::Remove { grid_pos } => {
+                // Waveで置いた障害物の撤去は、すでに取り除かれていれば同じマスにある別の障害物には手を付けない
+                let target = match event.target {
+                    Some(target) => obstacle_query.get(target).ok().filter(|(entity, ..)| !removed.contains(entity)),
+                    None => obstacle_query
+                        .iter()
+                        .find(|(entity, _, obstacle_pos, _)| obstacle_pos.covers(*grid_pos) && !removed.contains(entity)),
+                };
+                let Some((entity, kind, _, _)) = target else {
+                    continue;
+                };
+
+                // ワープゲートはもう一方の出入口も一緒に取り除かれる
+                removed.extend(clear_obstacle(&mut commands, &mut cleared_events, &obstacle_query, entity));
+                info!("Wave {} removed {:?} at {:?}", event.wave, kind, grid_pos);
+            }
+        }
+    }
+}
+
+/// いずれかのマスにモンスターの当たり判定が少しでも重なっているか
+fn is_any_monster_on_cells(cells: &[GridPosition], monster_query: &Query<(&Transform, &CollisionBox), With<Monster>>) -> bool {
+    cells.iter().any(|&cell| {
+        let center = grid_to_world(cell, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+        monster_query.iter().any(|(transform, collision_box)| {
+            let reach = (collision_box.size + Vec2::splat(GRID_SIZE)) / 2.0;
+            let distance = (transform.translation.xy() - center).abs();
+            distance.x < reach.x && distance.y < reach.y
+        })
+    })
+}
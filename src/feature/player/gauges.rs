@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::feature::monster::{MonsterDespawnEvent, DespawnCause};
+use crate::feature::monster::{MonsterDespawnEvent, DespawnCause, BonusEffect, LaneOverflowEvent, WaveCalledEarlyEvent};
 use crate::GameState;
 use crate::core::level;
 
@@ -75,6 +75,11 @@ impl VoidGauge {
         self.current = (self.current + amount).min(self.max);
     }
 
+    /// 虚を減少させる
+    pub fn reduce(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+    }
+
     /// ゲージが満タンか
     pub fn is_full(&self) -> bool {
         self.current >= self.max
@@ -90,17 +95,45 @@ impl VoidGauge {
 pub fn update_gauges_on_monster_event_system(
     mut gauges: ResMut<PlayerGauges>,
     mut events: MessageReader<MonsterDespawnEvent>,
+    mut overflow_events: MessageReader<LaneOverflowEvent>,
+    mut early_call_events: MessageReader<WaveCalledEarlyEvent>,
 ) {
     for event in events.read() {
         match event.cause {
             DespawnCause::ReachedGoal => {
-                gauges.spirit.add(level::SPIRIT_GAIN_PER_GOAL);
+                gauges.spirit.add(event.spirit_reward);
                 info!(
                     "Spirit +{}: {:.1}/{:.1}",
-                    level::SPIRIT_GAIN_PER_GOAL,
+                    event.spirit_reward,
                     gauges.spirit.current,
                     gauges.spirit.max
                 );
+
+                // 祠などから得たボーナス
+                for bonus in &event.bonuses {
+                    match bonus.effect {
+                        BonusEffect::Spirit(amount) => {
+                            gauges.spirit.add(amount);
+                            info!(
+                                "Spirit +{} ({:?}): {:.1}/{:.1}",
+                                amount,
+                                bonus.source,
+                                gauges.spirit.current,
+                                gauges.spirit.max
+                            );
+                        }
+                        BonusEffect::ReduceVoid(amount) => {
+                            gauges.void.reduce(amount);
+                            info!(
+                                "Void -{} ({:?}): {:.1}/{:.1}",
+                                amount,
+                                bonus.source,
+                                gauges.void.current,
+                                gauges.void.max
+                            );
+                        }
+                    }
+                }
             }
             DespawnCause::WaitExpired => {
                 gauges.void.add(level::VOID_GAIN_PER_DESPAWN);
@@ -111,8 +144,42 @@ pub fn update_gauges_on_monster_event_system(
                     gauges.void.max
                 );
             }
+            DespawnCause::WrongExit => {
+                gauges.void.add(level::VOID_GAIN_PER_WRONG_EXIT);
+                info!(
+                    "Wrong exit: Void +{} ({:.1}/{:.1})",
+                    level::VOID_GAIN_PER_WRONG_EXIT,
+                    gauges.void.current,
+                    gauges.void.max
+                );
+            }
         }
     }
+
+    // 待機列があふれたレーンは虚を増加させる
+    for event in overflow_events.read() {
+        gauges.void.add(level::VOID_GAIN_PER_LANE_OVERFLOW);
+        info!(
+            "Void +{} (lane overflow {:?}, {} queued): {:.1}/{:.1}",
+            level::VOID_GAIN_PER_LANE_OVERFLOW,
+            event.lane,
+            event.queue_length,
+            gauges.void.current,
+            gauges.void.max
+        );
+    }
+
+    // 次のWaveを早めに呼んだ場合は魂のボーナス
+    for event in early_call_events.read() {
+        gauges.spirit.add(event.spirit_bonus);
+        info!(
+            "Spirit +{} (wave {} called early): {:.1}/{:.1}",
+            event.spirit_bonus,
+            event.wave_index,
+            gauges.spirit.current,
+            gauges.spirit.max
+        );
+    }
 }
 
 /// Voidゲージが満タンになったらゲームオーバー
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use crate::feature::daily::DailyResult;
+
+/// デイリーチャレンジのリザルト画面のマーカー
+#[derive(Component)]
+pub struct DailyResultPanel;
+
+/// 画面中央にデイリーチャレンジの結果と自己ベストを表示
+pub fn setup_daily_result_ui_system(mut commands: Commands, result: Res<DailyResult>) {
+    let best_label = if result.is_new_best {
+        format!("New best: {}", result.best_score)
+    } else {
+        format!("Best: {}", result.best_score)
+    };
+
+    commands
+        .spawn((
+            DailyResultPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            for (label, font_size) in [
+                (format!("Daily Challenge {}", result.date), 28.0),
+                (format!("Score: {}", result.score), 22.0),
+                (best_label, 22.0),
+            ] {
+                parent.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            }
+        });
+}
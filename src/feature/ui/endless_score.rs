@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use crate::feature::endless::EndlessState;
+
+/// エンドレスモードのスコア表示用のマーカー
+#[derive(Component)]
+pub struct EndlessScoreText;
+
+/// 画面左上にエンドレスモードのスコアを表示
+pub fn setup_endless_score_ui_system(mut commands: Commands) {
+    commands.spawn((
+        EndlessScoreText,
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(20.0),
+            ..default()
+        },
+    ));
+}
+
+/// エンドレスモードのスコア表示を更新
+pub fn update_endless_score_ui_system(
+    endless_state: Res<EndlessState>,
+    mut text_query: Query<&mut Text, With<EndlessScoreText>>,
+) {
+    for mut text in text_query.iter_mut() {
+        **text = format!(
+            "Wave {}  Survived {}  Crossed {}  Score {}",
+            endless_state.waves_generated,
+            endless_state.waves_survived,
+            endless_state.monsters_crossed,
+            endless_state.score()
+        );
+    }
+}
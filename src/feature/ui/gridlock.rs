@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, grid_to_world};
+use crate::feature::monster::{ActiveGridlocks, GridlockKind};
+
+/// グリッドロック強調表示の色
+const HEAD_ON_HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+const CYCLE_HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.6, 0.1);
+
+/// グリッドロックに巻き込まれているマスを枠線で強調表示する
+pub fn draw_gridlock_highlight_system(
+    mut gizmos: Gizmos,
+    active: Res<ActiveGridlocks>,
+) {
+    for gridlock in &active.gridlocks {
+        let color = match gridlock.kind {
+            GridlockKind::HeadOn => HEAD_ON_HIGHLIGHT_COLOR,
+            GridlockKind::Cycle => CYCLE_HIGHLIGHT_COLOR,
+        };
+
+        for cell in &gridlock.cells {
+            let world_pos = grid_to_world(*cell, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+            gizmos.rect_2d(world_pos, Vec2::splat(GRID_SIZE * 0.95), color);
+        }
+    }
+}
@@ -1,3 +1,19 @@
 mod gauges;
+mod gridlock;
+mod spawn_telegraph;
+mod endless_score;
+mod daily_result;
+mod obstacle_direction;
+mod tide;
+mod obstacle_telegraph;
+mod shrine;
 
 pub use gauges::*;
+pub use gridlock::*;
+pub use spawn_telegraph::*;
+pub use endless_score::*;
+pub use daily_result::*;
+pub use obstacle_direction::*;
+pub use tide::*;
+pub use obstacle_telegraph::*;
+pub use shrine::*;
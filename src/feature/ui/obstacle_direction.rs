@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, grid_to_world};
+use crate::feature::obstacle::{ObstacleDirection, TeleporterEffect};
+
+/// 障害物の向きを示す矢印の色
+const OBSTACLE_ARROW_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+
+/// 一方通行ゲートやコンベア、向きの決まった風の向きを矢印で表示する
+pub fn draw_obstacle_direction_system(
+    mut gizmos: Gizmos,
+    query: Query<(&Transform, &ObstacleDirection)>,
+) {
+    for (transform, direction) in &query {
+        let center = transform.translation.xy();
+        let offset = direction.0.to_vector() * GRID_SIZE * 0.3;
+        gizmos.arrow_2d(center - offset, center + offset, OBSTACLE_ARROW_COLOR);
+    }
+}
+
+/// ワープゲートの出入口どうしをつなぐ線の色
+const TELEPORTER_LINK_COLOR: Color = Color::srgba(0.8, 0.4, 1.0, 0.4);
+
+/// ワープゲートの出入口どうしを線で結んで表示する
+pub fn draw_teleporter_link_system(
+    mut gizmos: Gizmos,
+    query: Query<(&Transform, &TeleporterEffect)>,
+) {
+    for (transform, teleporter) in &query {
+        let destination = grid_to_world(teleporter.destination, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+        gizmos.line_2d(transform.translation.xy(), destination, TELEPORTER_LINK_COLOR);
+    }
+}
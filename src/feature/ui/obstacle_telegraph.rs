@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, grid_to_world};
+use crate::feature::monster::MonsterSpawnQueue;
+use crate::feature::obstacle::{ObstacleAction, ObstacleEventQueue};
+
+/// 障害物が置かれる予告の色
+const PLACE_TELEGRAPH_COLOR: Color = Color::srgb(1.0, 0.9, 0.4);
+/// 障害物が取り除かれる予告の色
+const REMOVE_TELEGRAPH_COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+
+/// 予告の点滅間隔（秒）
+const TELEGRAPH_BLINK_INTERVAL: f32 = 0.25;
+
+/// Wave中の障害物イベントの予告を、対象のマスを点滅する枠で表示する
+///
+/// 置かれるマスは黄色の枠、取り除かれるマスは赤色の枠とバツ印で表示する
+pub fn draw_obstacle_event_telegraph_system(
+    mut gizmos: Gizmos,
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    event_queue: Res<ObstacleEventQueue>,
+) {
+    let Some(spawn_queue) = spawn_queue else {
+        return;
+    };
+
+    for (event, remaining) in event_queue.upcoming(spawn_queue.timer) {
+        // 点滅（消えている間は表示しない）
+        if (remaining / TELEGRAPH_BLINK_INTERVAL) as i32 % 2 == 1 {
+            continue;
+        }
+
+        let size = Vec2::splat(GRID_SIZE * 0.9);
+        match &event.action {
+            ObstacleAction::Place { obstacle, .. } => {
+                for cell in obstacle.occupied_cells() {
+                    let center = grid_to_world(cell, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+                    gizmos.rect_2d(Isometry2d::from_translation(center), size, PLACE_TELEGRAPH_COLOR);
+                }
+            }
+            ObstacleAction::Remove { grid_pos } => {
+                let center = grid_to_world(*grid_pos, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+                gizmos.rect_2d(Isometry2d::from_translation(center), size, REMOVE_TELEGRAPH_COLOR);
+                let half = size / 2.0;
+                gizmos.line_2d(center - half, center + half, REMOVE_TELEGRAPH_COLOR);
+                gizmos.line_2d(center + Vec2::new(-half.x, half.y), center + Vec2::new(half.x, -half.y), REMOVE_TELEGRAPH_COLOR);
+            }
+        }
+    }
+}
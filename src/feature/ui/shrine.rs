@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT, GridPosition, grid_to_world};
+use crate::feature::obstacle::{ObstaclePosition, ShrineEffect};
+
+/// 祠のご利益が届く範囲の枠の色
+const SHRINE_AREA_COLOR: Color = Color::srgba(0.95, 0.8, 0.3, 0.5);
+
+/// 祠のご利益が届く範囲を枠で表示する
+pub fn draw_shrine_area_system(
+    mut gizmos: Gizmos,
+    query: Query<(&ObstaclePosition, &ShrineEffect)>,
+) {
+    for (obstacle_pos, shrine_effect) in &query {
+        // 祠が覆うマスを囲む長方形を、範囲の分だけ広げる（フィールドの外は含めない）
+        let radius = shrine_effect.radius;
+        let min_x = obstacle_pos.cells.iter().map(|cell| cell.x).min().unwrap_or(0) - radius;
+        let max_x = obstacle_pos.cells.iter().map(|cell| cell.x).max().unwrap_or(0) + radius;
+        let min_y = obstacle_pos.cells.iter().map(|cell| cell.y).min().unwrap_or(0) - radius;
+        let max_y = obstacle_pos.cells.iter().map(|cell| cell.y).max().unwrap_or(0) + radius;
+        let min = GridPosition::new(min_x.max(0), min_y.max(0));
+        let max = GridPosition::new(max_x.min(FIELD_WIDTH - 1), max_y.min(FIELD_HEIGHT - 1));
+
+        let min_world = grid_to_world(min, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+        let max_world = grid_to_world(max, GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT);
+        let size = max_world - min_world + Vec2::splat(GRID_SIZE);
+        gizmos.rect_2d(Isometry2d::from_translation((min_world + max_world) / 2.0), size, SHRINE_AREA_COLOR);
+    }
+}
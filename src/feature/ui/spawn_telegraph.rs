@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::core::GRID_SIZE;
+use crate::feature::monster::{MonsterSpawnQueue, StagingLane, get_lane_edge_position};
+
+/// スポーン予告の表示エンティティ（レーンごとに1つ作り、使い回す）
+#[derive(Component)]
+pub struct SpawnTelegraph {
+    pub lane: StagingLane,
+}
+
+/// 予告表示の色（スポーン直前は警告色）
+const TELEGRAPH_COLOR: Color = Color::srgb(1.0, 0.9, 0.4);
+const TELEGRAPH_URGENT_COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+
+/// 警告色に切り替える残り時間（秒）
+const TELEGRAPH_URGENT_TIME: f32 = 1.0;
+
+/// フィールド端から予告表示までの距離
+const TELEGRAPH_MARGIN: f32 = GRID_SIZE * 0.5;
+
+/// ゲーム終了時にスポーン予告の表示エンティティを削除するシステム
+pub fn despawn_spawn_telegraph_system(mut commands: Commands, query: Query<Entity, With<SpawnTelegraph>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// スポーン予告を画面端のレーンごとに表示するシステム
+///
+/// 先読み時間内にスポーン予定のモンスターの種類・方向・残り時間を表示する。
+/// 表示エンティティはレーンごとに最初に必要になったときに作り、以降は文字と表示状態だけを更新する
+pub fn update_spawn_telegraph_system(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    mut existing: Query<(&SpawnTelegraph, &mut Text2d, &mut TextColor, &mut Visibility)>,
+) {
+    // レーン（進入方向と位置）ごとにまとめる
+    let mut lanes: HashMap<StagingLane, Vec<_>> = HashMap::new();
+    if let Some(spawn_queue) = &spawn_queue {
+        for upcoming in spawn_queue.upcoming_spawns(spawn_queue.telegraph_look_ahead) {
+            lanes
+                .entry(StagingLane::new(upcoming.direction, upcoming.grid_pos))
+                .or_default()
+                .push(upcoming);
+        }
+    }
+
+    // 種類と残り時間の表示と、その色
+    let mut labels: HashMap<StagingLane, (String, Color)> = HashMap::new();
+    for (lane, spawns) in &lanes {
+        let first = spawns[0];
+        let position = get_lane_edge_position(lane.direction, lane.grid_pos, TELEGRAPH_MARGIN);
+        let color = if first.remaining <= TELEGRAPH_URGENT_TIME {
+            TELEGRAPH_URGENT_COLOR
+        } else {
+            TELEGRAPH_COLOR
+        };
+
+        // 進行方向を矢印で表示
+        let arrow = lane.direction.to_vector() * GRID_SIZE * 0.4;
+        gizmos.arrow_2d(position.xy() - arrow / 2.0, position.xy() + arrow / 2.0, color);
+
+        // スポーンが近い順に並べる
+        let label = spawns
+            .iter()
+            .map(|s| format!("{:?} {:.1}s", s.kind, s.remaining))
+            .collect::<Vec<_>>()
+            .join("\n");
+        labels.insert(*lane, (label, color));
+    }
+
+    // 既存の表示を更新（予告がないレーンは隠す）
+    for (telegraph, mut text, mut text_color, mut visibility) in &mut existing {
+        match labels.remove(&telegraph.lane) {
+            Some((label, color)) => {
+                if text.0 != label {
+                    text.0 = label;
+                }
+                text_color.0 = color;
+                *visibility = Visibility::Inherited;
+            }
+            None => {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+
+    // 表示がまだないレーンだけ新しく作る
+    for (lane, (label, color)) in labels {
+        let position = get_lane_edge_position(lane.direction, lane.grid_pos, TELEGRAPH_MARGIN);
+        commands.spawn((
+            SpawnTelegraph { lane },
+            Text2d::new(label),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(color),
+            Visibility::Inherited,
+            Transform::from_translation(position.with_z(1.0)),
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use crate::core::{GRID_SIZE, FIELD_WIDTH, FIELD_HEIGHT};
+use crate::feature::monster::MonsterSpawnQueue;
+use crate::feature::obstacle::{Tide, TideAxis};
+
+/// 潮の帯の表示を現在の満ち引きに合わせて動かす
+pub fn update_tide_overlay_system(
+    spawn_queue: Option<Res<MonsterSpawnQueue>>,
+    mut query: Query<(&Tide, &mut Transform, &mut Sprite)>,
+) {
+    let Some(spawn_queue) = spawn_queue else {
+        return;
+    };
+
+    for (tide, mut transform, mut sprite) in &mut query {
+        let (start, end) = tide.definition.band(spawn_queue.timer);
+
+        // フィールドの外にはみ出した部分は表示しない
+        let field_cells = match tide.definition.axis {
+            TideAxis::Rows => FIELD_HEIGHT,
+            TideAxis::Columns => FIELD_WIDTH,
+        } as f32;
+        let start = start.clamp(0.0, field_cells);
+        let end = end.clamp(0.0, field_cells);
+
+        let center = ((start + end) / 2.0 - field_cells / 2.0) * GRID_SIZE;
+        let thickness = (end - start) * GRID_SIZE;
+
+        match tide.definition.axis {
+            TideAxis::Rows => {
+                transform.translation.x = 0.0;
+                transform.translation.y = center;
+                sprite.custom_size = Some(Vec2::new(FIELD_WIDTH as f32 * GRID_SIZE, thickness));
+            }
+            TideAxis::Columns => {
+                transform.translation.x = center;
+                transform.translation.y = 0.0;
+                sprite.custom_size = Some(Vec2::new(thickness, FIELD_HEIGHT as f32 * GRID_SIZE));
+            }
+        }
+    }
+}
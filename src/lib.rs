@@ -9,6 +9,8 @@ use feature::monster::MonsterPlugin;
 use feature::item::ItemPlugin;
 use feature::obstacle::ObstaclePlugin;
 use feature::player::PlayerPlugin;
+use feature::endless::EndlessPlugin;
+use feature::daily::DailyPlugin;
 
 #[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GameState {
@@ -29,6 +31,8 @@ impl Plugin for AppPlugin {
                 ItemPlugin,
                 ObstaclePlugin,
                 PlayerPlugin,
+                EndlessPlugin,
+                DailyPlugin,
             ));
     }
 }
@@ -1,5 +1,6 @@
 use bevy::{asset::AssetMetaCheck, prelude::*};
 use wave_crossing_game::AppPlugin;
+use wave_crossing_game::feature::monster::StageSelection;
 
 fn main() {
     // Set up better panic messages for WASM
@@ -16,5 +17,7 @@ fn main() {
             }),
         )
         .add_plugins(AppPlugin)
+        // --stage <path> でプレイするステージファイルを選択（例: --stage stages/endless.ron）
+        .insert_resource(StageSelection::from_args())
         .run();
 }